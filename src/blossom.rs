@@ -0,0 +1,571 @@
+//! Minimum weight perfect matching in general graphs, using [Edmonds' blossom
+//! algorithm](https://en.wikipedia.org/wiki/Blossom_algorithm) with the `O(n³)`
+//! primal-dual method described by Zvi Galil in "Efficient algorithms for finding
+//! maximum matching in graphs" (ACM Computing Surveys, 1986).
+//!
+//! Edges are identified by their index `k`, and each edge has two endpoints `2k` and
+//! `2k + 1`, so that `p ^ 1` is the other endpoint of the edge of endpoint `p`.
+
+use num_traits::Signed;
+
+const NONE: usize = usize::MAX;
+
+// Labels of vertices and top-level blossoms.
+const FREE: u8 = 0;
+const S: u8 = 1;
+const T: u8 = 2;
+// Temporary mark used while scanning for a blossom.
+const SCANNED: u8 = 4;
+
+enum Delta {
+    // No further improvement is possible.
+    Done,
+    // Make an edge between an S-vertex and a free vertex tight.
+    FreeEdge(usize),
+    // Make an edge between two S-blossoms tight.
+    SEdge(usize),
+    // Expand a T-blossom whose dual variable reaches zero.
+    Expand(usize),
+}
+
+struct Matching<C> {
+    vertices: usize,
+    // Edges with their weight, which is maximized.
+    edges: Vec<(usize, usize, C)>,
+    // Vertex of every endpoint.
+    endpoint: Vec<usize>,
+    // Remote endpoints of the edges incident to every vertex.
+    neighbours: Vec<Vec<usize>>,
+    // Remote endpoint of the matched edge of every vertex.
+    mate: Vec<usize>,
+    // The following vectors are indexed by vertices (below `vertices`) and blossoms.
+    label: Vec<u8>,
+    // Endpoint through which a label has been obtained.
+    label_end: Vec<usize>,
+    in_blossom: Vec<usize>,
+    parent: Vec<usize>,
+    children: Vec<Vec<usize>>,
+    base: Vec<usize>,
+    // Endpoints of the edges connecting consecutive children of a blossom.
+    endps: Vec<Vec<usize>>,
+    // Least-slack edge towards an S-blossom.
+    best_edge: Vec<usize>,
+    // Least-slack edges from an S-blossom towards other S-blossoms.
+    best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    dual: Vec<C>,
+    allow_edge: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+#[expect(clippy::many_single_char_names)]
+impl<C> Matching<C>
+where
+    C: Signed + Ord + Copy,
+{
+    fn new(vertices: usize, edges: Vec<(usize, usize, C)>) -> Self {
+        let max_weight = edges
+            .iter()
+            .map(|&(_, _, w)| w)
+            .max()
+            .unwrap_or_else(C::zero)
+            .max(C::zero());
+        let mut neighbours = vec![Vec::new(); vertices];
+        let mut endpoint = Vec::with_capacity(2 * edges.len());
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            neighbours[i].push(2 * k + 1);
+            neighbours[j].push(2 * k);
+            endpoint.extend([i, j]);
+        }
+        let mut dual = vec![max_weight; vertices];
+        dual.resize(2 * vertices, C::zero());
+        let edges_count = edges.len();
+        Self {
+            vertices,
+            edges,
+            endpoint,
+            neighbours,
+            mate: vec![NONE; vertices],
+            label: vec![FREE; 2 * vertices],
+            label_end: vec![NONE; 2 * vertices],
+            in_blossom: (0..vertices).collect(),
+            parent: vec![NONE; 2 * vertices],
+            children: vec![Vec::new(); 2 * vertices],
+            base: (0..vertices)
+                .chain(std::iter::repeat_n(NONE, vertices))
+                .collect(),
+            endps: vec![Vec::new(); 2 * vertices],
+            best_edge: vec![NONE; 2 * vertices],
+            best_edges: vec![None; 2 * vertices],
+            unused_blossoms: (vertices..2 * vertices).rev().collect(),
+            dual,
+            allow_edge: vec![false; edges_count],
+            queue: Vec::new(),
+        }
+    }
+
+    fn slack(&self, k: usize) -> C {
+        let (i, j, w) = self.edges[k];
+        self.dual[i] + self.dual[j] - w - w
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(b) = stack.pop() {
+            if b < self.vertices {
+                leaves.push(b);
+            } else {
+                stack.extend(&self.children[b]);
+            }
+        }
+        leaves
+    }
+
+    // Label the top-level blossom containing `w` through endpoint `p`. If it becomes
+    // a T-blossom, the blossom containing its mate becomes an S-blossom.
+    fn assign_label(&mut self, mut w: usize, mut t: u8, mut p: usize) {
+        loop {
+            let b = self.in_blossom[w];
+            self.label[w] = t;
+            self.label[b] = t;
+            self.label_end[w] = p;
+            self.label_end[b] = p;
+            self.best_edge[w] = NONE;
+            self.best_edge[b] = NONE;
+            if t == S {
+                let leaves = self.leaves(b);
+                self.queue.extend(leaves);
+                return;
+            }
+            let m = self.mate[self.base[b]];
+            (w, t, p) = (self.endpoint[m], S, m ^ 1);
+        }
+    }
+
+    // Trace back from S-vertices `v` and `w` to find either the base of a new
+    // blossom, or `NONE` if an augmenting path has been found.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = Vec::new();
+        let mut base = NONE;
+        while v != NONE {
+            let b = self.in_blossom[v];
+            if self.label[b] & SCANNED != 0 {
+                base = self.base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = S | SCANNED;
+            if self.label_end[b] == NONE {
+                v = NONE;
+            } else {
+                let t = self.in_blossom[self.endpoint[self.label_end[b]]];
+                v = self.endpoint[self.label_end[t]];
+            }
+            if w != NONE {
+                (v, w) = (w, v);
+            }
+        }
+        for b in path {
+            self.label[b] = S;
+        }
+        base
+    }
+
+    // Build a new blossom with the given base, closed by edge `k`.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self.unused_blossoms.pop().unwrap(); // Cannot fail
+        self.base[b] = base;
+        self.parent[b] = NONE;
+        self.parent[bb] = b;
+        let mut path = Vec::new();
+        let mut endps = Vec::new();
+        while bv != bb {
+            self.parent[bv] = b;
+            path.push(bv);
+            endps.push(self.label_end[bv]);
+            bv = self.in_blossom[self.endpoint[self.label_end[bv]]];
+        }
+        path.push(bb);
+        path.reverse();
+        endps.reverse();
+        endps.push(2 * k);
+        while bw != bb {
+            self.parent[bw] = b;
+            path.push(bw);
+            endps.push(self.label_end[bw] ^ 1);
+            bw = self.in_blossom[self.endpoint[self.label_end[bw]]];
+        }
+        self.children[b] = path;
+        self.endps[b] = endps;
+        self.label[b] = S;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = C::zero();
+        for v in self.leaves(b) {
+            if self.label[self.in_blossom[v]] == T {
+                // Former T-vertices become S-vertices.
+                self.queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+        // Compute the least-slack edges towards other S-blossoms.
+        let mut best_edge_to = vec![NONE; 2 * self.vertices];
+        for bv in self.children[b].clone() {
+            let candidates = match self.best_edges[bv].take() {
+                Some(list) => list,
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .flat_map(|v| self.neighbours[v].iter().map(|p| p / 2))
+                    .collect(),
+            };
+            for k in candidates {
+                let (i, j, _) = self.edges[k];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == S
+                    && (best_edge_to[bj] == NONE || self.slack(k) < self.slack(best_edge_to[bj]))
+                {
+                    best_edge_to[bj] = k;
+                }
+            }
+            self.best_edge[bv] = NONE;
+        }
+        let best_edges = best_edge_to
+            .into_iter()
+            .filter(|&k| k != NONE)
+            .collect::<Vec<_>>();
+        self.best_edge[b] = NONE;
+        for &k in &best_edges {
+            if self.best_edge[b] == NONE || self.slack(k) < self.slack(self.best_edge[b]) {
+                self.best_edge[b] = k;
+            }
+        }
+        self.best_edges[b] = Some(best_edges);
+    }
+
+    // Expand a top-level blossom into its children.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        let children = std::mem::take(&mut self.children[b]);
+        let endps = std::mem::take(&mut self.endps[b]);
+        for &s in &children {
+            self.parent[s] = NONE;
+            if s < self.vertices {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == C::zero() {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+        if !end_stage && self.label[b] == T {
+            // Relabel the children which are on the alternating path through
+            // the blossom, from the entry child to the base.
+            let len = children.len().cast_signed();
+            let at = |v: &[usize], j: isize| v[j.rem_euclid(len).cast_unsigned()];
+            let entry_child = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+            let mut j = children
+                .iter()
+                .position(|&c| c == entry_child)
+                .unwrap() // Cannot fail
+                .cast_signed();
+            let (step, trick): (isize, usize) = if j & 1 == 1 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let mut p = self.label_end[b];
+            while j != 0 {
+                self.label[self.endpoint[p ^ 1]] = FREE;
+                self.label[self.endpoint[at(&endps, j - trick.cast_signed()) ^ trick ^ 1]] = FREE;
+                self.assign_label(self.endpoint[p ^ 1], T, p);
+                self.allow_edge[at(&endps, j - trick.cast_signed()) / 2] = true;
+                j += step;
+                p = at(&endps, j - trick.cast_signed()) ^ trick;
+                self.allow_edge[p / 2] = true;
+                j += step;
+            }
+            let bv = at(&children, j);
+            self.label[self.endpoint[p ^ 1]] = T;
+            self.label[bv] = T;
+            self.label_end[self.endpoint[p ^ 1]] = p;
+            self.label_end[bv] = p;
+            self.best_edge[bv] = NONE;
+            j += step;
+            // The other children are either free, or reached through a single vertex.
+            while at(&children, j) != entry_child {
+                let bv = at(&children, j);
+                j += step;
+                if self.label[bv] == S {
+                    continue;
+                }
+                if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.label[v] != FREE) {
+                    self.label[v] = FREE;
+                    self.label[self.endpoint[self.mate[self.base[bv]]]] = FREE;
+                    self.assign_label(v, T, self.label_end[v]);
+                }
+            }
+        }
+        self.label[b] = FREE;
+        self.label_end[b] = NONE;
+        self.base[b] = NONE;
+        self.best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused_blossoms.push(b);
+    }
+
+    // Swap matched and unmatched edges along the path from vertex `v` to the base of
+    // blossom `b`, and rotate the blossom so that `v` becomes its new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.parent[t] != b {
+            t = self.parent[t];
+        }
+        if t >= self.vertices {
+            self.augment_blossom(t, v);
+        }
+        let len = self.children[b].len().cast_signed();
+        let at = |v: &[usize], j: isize| v[j.rem_euclid(len).cast_unsigned()];
+        let i = self.children[b].iter().position(|&c| c == t).unwrap(); // Cannot fail
+        let mut j = i.cast_signed();
+        let (step, trick): (isize, usize) = if j & 1 == 1 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        while j != 0 {
+            j += step;
+            let t = at(&self.children[b], j);
+            let p = at(&self.endps[b], j - trick.cast_signed()) ^ trick;
+            if t >= self.vertices {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += step;
+            let t = at(&self.children[b], j);
+            if t >= self.vertices {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+        self.children[b].rotate_left(i);
+        self.endps[b].rotate_left(i);
+        self.base[b] = self.base[self.children[b][0]];
+    }
+
+    // Augment the matching along the path going through edge `k`.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.vertices {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.label_end[bs] == NONE {
+                    break;
+                }
+                let t = self.endpoint[self.label_end[bs]];
+                let bt = self.in_blossom[t];
+                s = self.endpoint[self.label_end[bt]];
+                let j = self.endpoint[self.label_end[bt] ^ 1];
+                if bt >= self.vertices {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+
+    // Grow the alternating trees from the queued S-vertices. Return `true` if
+    // the matching has been augmented.
+    fn scan_queue(&mut self) -> bool {
+        while let Some(v) = self.queue.pop() {
+            for index in 0..self.neighbours[v].len() {
+                let p = self.neighbours[v][index];
+                let (k, w) = (p / 2, self.endpoint[p]);
+                if self.in_blossom[v] == self.in_blossom[w] {
+                    continue;
+                }
+                let mut slack = None;
+                if !self.allow_edge[k] {
+                    let s = self.slack(k);
+                    if s <= C::zero() {
+                        self.allow_edge[k] = true;
+                    } else {
+                        slack = Some(s);
+                    }
+                }
+                let bw = self.in_blossom[w];
+                if let Some(slack) = slack {
+                    // Remember the least-slack edges for the dual update.
+                    let b = if self.label[bw] == S {
+                        self.in_blossom[v]
+                    } else if self.label[w] == FREE {
+                        w
+                    } else {
+                        continue;
+                    };
+                    if self.best_edge[b] == NONE || slack < self.slack(self.best_edge[b]) {
+                        self.best_edge[b] = k;
+                    }
+                } else if self.label[bw] == FREE {
+                    self.assign_label(w, T, p ^ 1);
+                } else if self.label[bw] == S {
+                    let base = self.scan_blossom(v, w);
+                    if base == NONE {
+                        self.augment_matching(k);
+                        return true;
+                    }
+                    self.add_blossom(base, k);
+                } else if self.label[w] == FREE {
+                    // Vertex inside a T-blossom reached through a tight edge.
+                    self.label[w] = T;
+                    self.label_end[w] = p ^ 1;
+                }
+            }
+        }
+        false
+    }
+
+    // Find the largest dual update which keeps the dual solution feasible.
+    fn compute_delta(&self) -> (C, Delta) {
+        let two = C::one() + C::one();
+        let mut best: Option<(C, Delta)> = None;
+        let mut consider = |d: C, delta: Delta| {
+            if best.as_ref().is_none_or(|(b, _)| d < *b) {
+                best = Some((d, delta));
+            }
+        };
+        for v in 0..self.vertices {
+            if self.label[self.in_blossom[v]] == FREE && self.best_edge[v] != NONE {
+                consider(
+                    self.slack(self.best_edge[v]),
+                    Delta::FreeEdge(self.best_edge[v]),
+                );
+            }
+        }
+        for b in 0..2 * self.vertices {
+            if self.parent[b] == NONE && self.label[b] == S && self.best_edge[b] != NONE {
+                let k = self.best_edge[b];
+                consider(self.slack(k) / two, Delta::SEdge(k));
+            }
+        }
+        for b in self.vertices..2 * self.vertices {
+            if self.base[b] != NONE && self.parent[b] == NONE && self.label[b] == T {
+                consider(self.dual[b], Delta::Expand(b));
+            }
+        }
+        best.unwrap_or_else(|| {
+            let min = self.dual[..self.vertices].iter().copied().min();
+            (min.unwrap_or_else(C::zero).max(C::zero()), Delta::Done)
+        })
+    }
+
+    // Compute a maximum-cardinality matching of maximum weight.
+    fn solve(&mut self) {
+        for _ in 0..self.vertices {
+            self.label.fill(FREE);
+            self.best_edge.fill(NONE);
+            self.best_edges[self.vertices..].fill(None);
+            self.allow_edge.fill(false);
+            self.queue.clear();
+            for v in 0..self.vertices {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == FREE {
+                    self.assign_label(v, S, NONE);
+                }
+            }
+            let augmented = loop {
+                if self.scan_queue() {
+                    break true;
+                }
+                let (delta, kind) = self.compute_delta();
+                for v in 0..self.vertices {
+                    match self.label[self.in_blossom[v]] {
+                        S => self.dual[v] = self.dual[v] - delta,
+                        T => self.dual[v] = self.dual[v] + delta,
+                        _ => (),
+                    }
+                }
+                for b in self.vertices..2 * self.vertices {
+                    if self.base[b] != NONE && self.parent[b] == NONE {
+                        match self.label[b] {
+                            S => self.dual[b] = self.dual[b] + delta,
+                            T => self.dual[b] = self.dual[b] - delta,
+                            _ => (),
+                        }
+                    }
+                }
+                match kind {
+                    Delta::Done => break false,
+                    Delta::FreeEdge(k) => {
+                        self.allow_edge[k] = true;
+                        let (i, j, _) = self.edges[k];
+                        let i = if self.label[self.in_blossom[i]] == FREE {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    Delta::SEdge(k) => {
+                        self.allow_edge[k] = true;
+                        self.queue.push(self.edges[k].0);
+                    }
+                    Delta::Expand(b) => self.expand_blossom(b, false),
+                }
+            };
+            if !augmented {
+                break;
+            }
+            // Expand the S-blossoms whose dual variable is zero.
+            for b in self.vertices..2 * self.vertices {
+                if self.parent[b] == NONE
+                    && self.base[b] != NONE
+                    && self.label[b] == S
+                    && self.dual[b] == C::zero()
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
+
+/// Compute a minimum weight perfect matching in a general graph whose vertices are
+/// `0..vertices`. Return the mate of every vertex, or `None` if the graph has no
+/// perfect matching.
+pub(crate) fn min_weight_perfect_matching<C>(
+    vertices: usize,
+    edges: &[(usize, usize, C)],
+) -> Option<Vec<usize>>
+where
+    C: Signed + Ord + Copy,
+{
+    // A maximum-cardinality matching maximizing `max - cost` is a perfect matching
+    // with a minimal cost if a perfect matching exists.
+    let max = edges
+        .iter()
+        .map(|&(_, _, c)| c)
+        .max()
+        .unwrap_or_else(C::zero);
+    let edges = edges.iter().map(|&(i, j, c)| (i, j, max - c)).collect();
+    let mut matching = Matching::new(vertices, edges);
+    matching.solve();
+    matching
+        .mate
+        .iter()
+        .map(|&p| (p != NONE).then(|| matching.endpoint[p]))
+        .collect()
+}
@@ -263,12 +263,7 @@ where
             .map(|(k, (ps, _))| (k, ps.into_iter().collect()))
             .collect();
         (
-            AstarSolution {
-                sinks: sinks.into_iter().collect(),
-                parents,
-                current: vec![],
-                terminated: false,
-            },
+            AstarSolution::new(sinks.into_iter().collect(), parents),
             cost,
         )
     })
@@ -340,7 +335,7 @@ impl<K: Ord> Ord for SmallestCostHolder<K> {
     }
 }
 
-/// Iterator structure created by the `astar_bag` function.
+/// Iterator structure created by the `astar_bag`, `dijkstra_bag`, and `bfs_bag` functions.
 #[derive(Clone)]
 pub struct AstarSolution<N> {
     sinks: Vec<usize>,
@@ -350,6 +345,15 @@ pub struct AstarSolution<N> {
 }
 
 impl<N: Clone + Eq + Hash> AstarSolution<N> {
+    pub(crate) const fn new(sinks: Vec<usize>, parents: Vec<(N, Vec<usize>)>) -> Self {
+        Self {
+            sinks,
+            parents,
+            current: vec![],
+            terminated: false,
+        }
+    }
+
     fn complete(&mut self) {
         loop {
             let ps = match self.current.last() {
@@ -1,11 +1,14 @@
-//! Compute a shortest path using the [breadth-first search
+//! Compute a shortest path (or all shortest paths) using the [breadth-first search
 //! algorithm](https://en.wikipedia.org/wiki/Breadth-first_search).
 
+use super::astar::AstarSolution;
 use super::reverse_path;
 use crate::{FxIndexMap, FxIndexSet, NodeRefs};
-use indexmap::map::Entry::Vacant;
+use indexmap::map::Entry::{Occupied, Vacant};
+use num_traits::Zero;
 use std::hash::Hash;
 use std::iter::FusedIterator;
+use std::ops::Add;
 
 /// Compute a shortest path using the [breadth-first search
 /// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search).
@@ -132,6 +135,186 @@ where
     bfs_core(&start, successors, |n| start.contains(n), false)
 }
 
+/// Compute all shortest paths using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search).
+///
+/// Whereas `bfs` (non-deterministic-ally) returns a single shortest
+/// path, `bfs_bag` returns all shortest paths (in a
+/// non-deterministic order).
+///
+/// The shortest paths starting from `start` up to a node for which `success` returns `true` are
+/// computed and returned in an iterator, wrapped in a `Some`. If no paths are found, `None` is
+/// returned.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+///
+/// A node will never be included twice in the path as determined by the `Eq` relationship.
+///
+/// Each path comprises both a start and an end node. Note that different paths may have
+/// different start and end nodes, but they all have the same length.
+///
+/// If only the number of shortest paths is needed, [`count_shortest_paths`] computes it
+/// without enumerating them.
+///
+/// # Example
+///
+/// We will search all the shortest paths on a chess board to go from (1, 1) to (4, 6) doing only
+/// knight moves.
+///
+/// ```
+/// use pathfinding::prelude::bfs_bag;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let solutions = bfs_bag(&(1, 1),
+///                         |&(x, y)| vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///                                        (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)],
+///                         |&p| p == GOAL);
+/// let solutions = solutions.expect("no path found").collect::<Vec<_>>();
+/// assert_eq!(solutions.len(), 24);
+/// assert!(solutions.iter().all(|path| path.len() == 5));
+/// ```
+pub fn bfs_bag<'a, N, S, FN, IN, FS>(
+    start: S,
+    successors: FN,
+    success: FS,
+) -> Option<AstarSolution<N>>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let (parents, sinks) = bfs_bag_core(&start.into(), successors, success);
+    (!sinks.is_empty()).then(|| {
+        AstarSolution::new(
+            sinks,
+            parents
+                .into_iter()
+                .map(|(node, (ps, _))| (node, ps))
+                .collect(),
+        )
+    })
+}
+
+/// Count the shortest paths using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search).
+///
+/// This function returns the number of paths that [`bfs_bag`] would enumerate, without
+/// building them. It runs in a time proportional to the number of nodes and edges
+/// explored, regardless of the number of shortest paths. If no path exists, zero is
+/// returned.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+/// - `one` is the count of a single path, and determines the type used to count paths. As
+///   the number of shortest paths can grow exponentially with their length, a wide integer
+///   type such as `u128` or an arbitrary precision integer may be needed to prevent
+///   overflows.
+///
+/// # Example
+///
+/// We will count the shortest paths on a chess board to go from (1, 1) to (4, 6) doing only
+/// knight moves.
+///
+/// ```
+/// use pathfinding::prelude::count_shortest_paths;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let n = count_shortest_paths(&(1, 1),
+///                              |&(x, y)| vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///                                             (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)],
+///                              |&p| p == GOAL,
+///                              1usize);
+/// assert_eq!(n, 24);
+/// ```
+pub fn count_shortest_paths<'a, N, C, S, FN, IN, FS>(
+    start: S,
+    successors: FN,
+    success: FS,
+    one: C,
+) -> C
+where
+    N: Eq + Hash + Clone + 'a,
+    C: Zero + Add<Output = C> + Clone,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let (parents, sinks) = bfs_bag_core(&start.into(), successors, success);
+    if sinks.is_empty() {
+        return C::zero();
+    }
+    // Starting nodes come first and have no parents, and parents are always
+    // located before their children in the map, so the number of paths leading
+    // to every node can be computed in one pass.
+    let starts = parents.values().take_while(|(ps, _)| ps.is_empty()).count();
+    let mut counts = vec![one; starts];
+    counts.reserve(parents.len() - starts);
+    for (ps, _) in parents.values().skip(starts) {
+        let count = ps.iter().fold(C::zero(), |acc, &p| acc + counts[p].clone());
+        counts.push(count);
+    }
+    sinks
+        .into_iter()
+        .fold(C::zero(), |acc, s| acc + counts[s].clone())
+}
+
+/// Explore the graph level by level, recording for every node all its parents
+/// at the previous level as well as its depth, and stop after the first level
+/// containing a node satisfying `success`. Return the parents map and the indices
+/// of the successful nodes.
+#[expect(clippy::type_complexity)]
+fn bfs_bag_core<'a, N, FN, IN, FS>(
+    start: &NodeRefs<'a, N>,
+    mut successors: FN,
+    mut success: FS,
+) -> (FxIndexMap<N, (Vec<usize>, usize)>, Vec<usize>)
+where
+    N: Eq + Hash + Clone + 'a,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents: FxIndexMap<N, (Vec<usize>, usize)> = FxIndexMap::default();
+    parents.extend(start.into_iter().map(|n| (n.clone(), (Vec::new(), 0))));
+    let mut sinks = (0..parents.len())
+        .filter(|&i| success(parents.get_index(i).unwrap().0)) // Cannot fail
+        .collect::<Vec<_>>();
+    let mut level_start = 0;
+    let mut depth = 0;
+    while sinks.is_empty() && level_start < parents.len() {
+        let level_end = parents.len();
+        depth += 1;
+        for i in level_start..level_end {
+            for successor in successors(parents.get_index(i).unwrap().0) {
+                match parents.entry(successor) {
+                    Vacant(e) => {
+                        if success(e.key()) {
+                            sinks.push(e.index());
+                        }
+                        e.insert((vec![i], depth));
+                    }
+                    Occupied(mut e) => {
+                        let (ps, d) = e.get_mut();
+                        if *d == depth && ps.last() != Some(&i) {
+                            ps.push(i);
+                        }
+                    }
+                }
+            }
+        }
+        level_start = level_end;
+    }
+    (parents, sinks)
+}
+
 /// Compute a shortest path using the [breadth-first search
 /// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search) with
 /// [bidirectional search](https://en.wikipedia.org/wiki/Bidirectional_search).
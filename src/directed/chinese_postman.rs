@@ -0,0 +1,123 @@
+//! Find the shortest closed walk going through every edge of a directed graph
+//! at least once, also known as the [Chinese postman
+//! problem](https://en.wikipedia.org/wiki/Chinese_postman_problem) or route inspection.
+
+use super::dijkstra::dijkstra_all;
+use super::eulerian::eulerian_circuit;
+use super::index_graph;
+use crate::kuhn_munkres::kuhn_munkres_min;
+use crate::matrix::Matrix;
+use num_traits::{Bounded, Signed};
+use std::hash::Hash;
+use std::iter::Sum;
+
+/// Find the shortest closed walk going through every edge of a directed
+/// weighted graph at least once.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor, including possibly nodes that were not present in `nodes`.
+///   A successor appearing several times denotes parallel edges. Costs must not be negative.
+///
+/// Nodes with more incoming than outgoing edges are linked to nodes with more outgoing
+/// than incoming edges by shortest paths, chosen so that their total cost is minimal
+/// using the [Kuhn-Munkres algorithm](crate::kuhn_munkres::kuhn_munkres_min).
+/// The edges of those shortest paths are traversed once more, and an [Eulerian
+/// circuit](super::eulerian::eulerian_circuit) of the resulting graph is returned. The
+/// successors of a given node are requested only once.
+///
+/// The walk is returned as the list of visited nodes, starting with the first node having
+/// edges in the order of `nodes` first, then in the order in which they are discovered
+/// through `successors`. The last node is the same as the first one. The total cost of the
+/// walk is returned as well. An empty walk is returned if there are no edges.
+///
+/// The function returns `None` if there is no such walk, because some edges cannot be
+/// reached from other edges.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::chinese_postman;
+///
+/// // 1 -> 2 -> 3 -> 1, 1 -> 3: the edge 3 -> 1 has to be traversed twice.
+/// let successors = |&n: &u32| match n {
+///     1 => vec![(2, 1), (3, 5)],
+///     2 => vec![(3, 1)],
+///     _ => vec![(1, 2)],
+/// };
+/// let (walk, cost) = chinese_postman(&[1], successors).unwrap();
+/// assert_eq!(walk, vec![1, 2, 3, 1, 3, 1]);
+/// assert_eq!(cost, 11);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn chinese_postman<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Bounded + Sum<C> + Signed + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (discovered, edges) = index_graph(nodes, successors, |discovered, (s, c)| {
+        (discovered.insert_full(s).0, c)
+    });
+    let mut balances = edges
+        .iter()
+        .map(|succs| succs.len().cast_signed())
+        .collect::<Vec<_>>();
+    for &(s, _) in edges.iter().flatten() {
+        balances[s] -= 1;
+    }
+    // Extra paths must start from nodes with missing outgoing edges, and end
+    // at nodes with missing incoming edges. Nodes are repeated as needed.
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    for (n, &balance) in balances.iter().enumerate() {
+        let copies = std::iter::repeat_n(n, balance.unsigned_abs());
+        if balance < 0 {
+            starts.extend(copies);
+        } else {
+            ends.extend(copies);
+        }
+    }
+    let mut sources = starts.clone();
+    sources.dedup();
+    let parents = sources
+        .iter()
+        .map(|source| dijkstra_all(source, |&n| edges[n].clone()))
+        .collect::<Vec<_>>();
+    let source_index = |n: usize| sources.binary_search(&n).unwrap(); // Cannot fail
+    // If an end cannot be reached from a start, the graph is not strongly connected.
+    let distances = starts
+        .iter()
+        .flat_map(|&s| {
+            let parents = &parents[source_index(s)];
+            ends.iter().map(move |e| parents.get(e).map(|&(_, c)| c))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let distances = Matrix::from_vec(starts.len(), ends.len(), distances).unwrap(); // Cannot fail
+    let (_, mates) = kuhn_munkres_min(&distances);
+    let mut cost = edges
+        .iter()
+        .flatten()
+        .fold(C::zero(), |acc, &(_, c)| acc + c);
+    let mut walk_edges = edges
+        .iter()
+        .map(|succs| succs.iter().map(|&(s, _)| s).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for (&start, &mate) in starts.iter().zip(&mates) {
+        let parents = &parents[source_index(start)];
+        let mut node = ends[mate];
+        cost = cost + parents[&node].1;
+        while node != start {
+            let parent = parents[&node].0;
+            walk_edges[parent].push(node);
+            node = parent;
+        }
+    }
+    let indices = (0..discovered.len()).collect::<Vec<_>>();
+    let walk = eulerian_circuit(&indices, |&n| walk_edges[n].clone()).ok()?;
+    Some((
+        walk.into_iter().map(|n| discovered[n].clone()).collect(),
+        cost,
+    ))
+}
@@ -1,41 +1,20 @@
 //! Count the total number of possible paths to reach a destination.
 
 use std::hash::Hash;
+use std::ops::Add;
 
-use rustc_hash::FxHashMap;
+use num_traits::Zero;
 
-fn cached_count_paths<T, FN, IN, FS>(
-    start: T,
-    successors: &mut FN,
-    success: &mut FS,
-    cache: &mut FxHashMap<T, usize>,
-) -> usize
-where
-    T: Eq + Hash,
-    FN: FnMut(&T) -> IN,
-    IN: IntoIterator<Item = T>,
-    FS: FnMut(&T) -> bool,
-{
-    if let Some(&n) = cache.get(&start) {
-        return n;
-    }
-
-    let count = if success(&start) {
-        1
-    } else {
-        successors(&start)
-            .into_iter()
-            .map(|successor| cached_count_paths(successor, successors, success, cache))
-            .sum()
-    };
-
-    cache.insert(start, count);
-
-    count
-}
+use crate::FxIndexMap;
 
 /// Count the total number of possible paths to reach a destination. There must be no loops
-/// in the graph, or the function will overflow its stack.
+/// in the graph.
+///
+/// This is a shortcut for [`try_count_paths`] counting paths with a `usize`.
+///
+/// # Panics
+///
+/// This function panics if a loop is detected in the graph.
 ///
 /// # Example
 ///
@@ -55,17 +34,118 @@ where
 /// );
 /// assert_eq!(n, 3432);
 /// ```
-pub fn count_paths<T, FN, IN, FS>(start: T, mut successors: FN, mut success: FS) -> usize
+pub fn count_paths<T, FN, IN, FS>(start: T, successors: FN, success: FS) -> usize
 where
     T: Eq + Hash,
     FN: FnMut(&T) -> IN,
     IN: IntoIterator<Item = T>,
     FS: FnMut(&T) -> bool,
 {
-    cached_count_paths(
-        start,
-        &mut successors,
-        &mut success,
-        &mut FxHashMap::default(),
-    )
+    try_count_paths(start, successors, success, 1)
+        .unwrap_or_else(|_| panic!("a loop has been detected in the graph"))
+}
+
+/// Count the total number of possible paths to reach a destination, using
+/// any numeric type for the result.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+/// - `success` checks whether the goal has been reached. Successors of a node
+///   for which `success` returns `true` are not explored.
+/// - `one` is the count of paths going from a successful node to itself.
+///
+/// The count type `C` can be any type implementing [`Zero`] and [`Add`], such as
+/// `u128` for very large counts, or a modular arithmetic type if only the count
+/// modulo some number is needed.
+///
+/// The graph is explored using an explicit stack, so arbitrarily long paths can
+/// be handled without risking a stack overflow.
+///
+/// # Errors
+///
+/// If a loop is found in the part of the graph reachable from `start` without going
+/// through a successful node, `Err(n)` is returned with `n` being a node involved in
+/// the loop.
+///
+/// # Example
+///
+/// On a 50x50 board, find the total paths from the bottom-left square to the
+/// top-right square. This number would not fit in a `u64`.
+///
+/// ```
+/// use pathfinding::prelude::try_count_paths;
+///
+/// let n = try_count_paths(
+///     (0, 0),
+///     |&(x, y)| {
+///         [(x + 1, y), (x, y + 1)]
+///             .into_iter()
+///             .filter(|&(x, y)| x < 50 && y < 50)
+///     },
+///     |&c| c == (49, 49),
+///     1u128,
+/// );
+/// assert_eq!(n, Ok(25_477_612_258_980_856_902_730_428_600));
+/// ```
+///
+/// A loop in the graph is reported as an error:
+///
+/// ```
+/// use pathfinding::prelude::try_count_paths;
+///
+/// let n = try_count_paths(0, |&n| [(n + 1) % 5], |&n| n == 10, 1usize);
+/// assert!(n.is_err());
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn try_count_paths<T, C, FN, IN, FS>(
+    start: T,
+    mut successors: FN,
+    mut success: FS,
+    one: C,
+) -> Result<C, T>
+where
+    T: Eq + Hash,
+    C: Zero + Add<Output = C> + Clone,
+    FN: FnMut(&T) -> IN,
+    IN: IntoIterator<Item = T>,
+    FS: FnMut(&T) -> bool,
+{
+    // Every node in `cache` is either being explored (`None`), or has
+    // a known paths count.
+    let mut cache: FxIndexMap<T, Option<C>> = FxIndexMap::default();
+    if success(&start) {
+        return Ok(one);
+    }
+    let (index, _) = cache.insert_full(start, None);
+    let mut stack = vec![(
+        index,
+        successors(cache.get_index(index).unwrap().0).into_iter(), // Cannot fail
+        C::zero(),
+    )];
+    loop {
+        let (_, iter, count) = stack.last_mut().unwrap(); // Cannot fail
+        if let Some(node) = iter.next() {
+            match cache.get(&node) {
+                Some(Some(c)) => *count = count.clone() + c.clone(),
+                Some(None) => return Err(node),
+                None => {
+                    if success(&node) {
+                        *count = count.clone() + one.clone();
+                        cache.insert(node, Some(one.clone()));
+                    } else {
+                        let (index, _) = cache.insert_full(node, None);
+                        let iter = successors(cache.get_index(index).unwrap().0).into_iter(); // Cannot fail
+                        stack.push((index, iter, C::zero()));
+                    }
+                }
+            }
+        } else {
+            let (index, _, count) = stack.pop().unwrap(); // Cannot fail
+            let Some((_, _, parent_count)) = stack.last_mut() else {
+                return Ok(count);
+            };
+            *parent_count = parent_count.clone() + count.clone();
+            *cache.get_index_mut(index).unwrap().1 = Some(count); // Cannot fail
+        }
+    }
 }
@@ -1,5 +1,9 @@
 //! Identify a cycle in an infinite sequence.
 
+use rustc_hash::FxHashMap;
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::hash::Hash;
+
 /// Identify a cycle in an infinite sequence using Floyd's algorithm (partial version).
 /// Return the cycle size, an element in the cycle, and an upper bound on the index of
 /// the first element.
@@ -140,3 +144,174 @@ where
     }
     (lam, hare, mu)
 }
+
+// Compute the states of the sequence until a state with an already seen key is found,
+// or until the state at index `limit` is known. Return the states, along with the index
+// of the first state of the cycle if a cycle has been found.
+fn record_states<T, K, FS, FK>(
+    start: T,
+    mut successor: FS,
+    mut key: FK,
+    limit: usize,
+) -> (Vec<T>, Option<usize>)
+where
+    T: Clone,
+    K: Eq + Hash,
+    FS: FnMut(T) -> T,
+    FK: FnMut(&T) -> K,
+{
+    let mut seen = FxHashMap::default();
+    seen.insert(key(&start), 0);
+    let mut states = vec![start];
+    while states.len() <= limit {
+        let next = successor(states[states.len() - 1].clone());
+        match seen.entry(key(&next)) {
+            Occupied(e) => return (states, Some(*e.get())),
+            Vacant(e) => {
+                e.insert(states.len());
+            }
+        }
+        states.push(next);
+    }
+    (states, None)
+}
+
+/// Identify a cycle in an infinite sequence by remembering the states already seen.
+/// Return the cycle size, the first element, and the index of first element.
+///
+/// Unlike [`floyd`] and [`brent`], the successor function is called only once per
+/// distinct state, at the expense of storing all those states.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::hash_cycle;
+///
+/// assert_eq!(hash_cycle(-10, |x| (x + 5) % 6 + 3), (3, 6, 2));
+/// ```
+///
+/// # Warning
+///
+/// If no cycle exist, this function loops forever.
+pub fn hash_cycle<T, FS>(start: T, successor: FS) -> (usize, T, usize)
+where
+    T: Clone + Eq + Hash,
+    FS: FnMut(T) -> T,
+{
+    hash_cycle_by_key(start, successor, T::clone)
+}
+
+/// Identify a cycle in an infinite sequence by remembering the keys of the states
+/// already seen. Return the cycle size, the first element, and the index of first element.
+///
+/// - `start` is the first state of the sequence.
+/// - `successor` computes the next state of the sequence.
+/// - `key` extracts the part of a state which determines the rest of the sequence.
+///   Two states with the same key are considered identical.
+///
+/// The successor function is called only once per distinct state, at the expense of
+/// storing all those states.
+///
+/// # Example
+///
+/// A state made of a position and a step counter, where the counter does not
+/// influence the next position:
+///
+/// ```
+/// use pathfinding::prelude::hash_cycle_by_key;
+///
+/// let (lam, first, mu) = hash_cycle_by_key((1, 0), |(x, n)| (x * 2 % 7, n + 1), |&(x, _)| x);
+/// assert_eq!((lam, first, mu), (3, (1, 0), 0));
+/// ```
+///
+/// # Warning
+///
+/// If no cycle exist, this function loops forever.
+#[expect(clippy::missing_panics_doc)]
+pub fn hash_cycle_by_key<T, K, FS, FK>(start: T, successor: FS, key: FK) -> (usize, T, usize)
+where
+    T: Clone,
+    K: Eq + Hash,
+    FS: FnMut(T) -> T,
+    FK: FnMut(&T) -> K,
+{
+    let (mut states, mu) = record_states(start, successor, key, usize::MAX);
+    let mu = mu.unwrap(); // Cannot fail
+    let lam = states.len() - mu;
+    (lam, states.swap_remove(mu), mu)
+}
+
+/// Compute the state at index `n` of an eventually periodic sequence, where `start`
+/// is at index 0.
+///
+/// States are computed and remembered until a cycle is found, and the state at index
+/// `n` is then deduced from the cycle. This makes it possible to use very large values
+/// of `n`, as long as the cycle is reached in a reasonable number of steps.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::nth_state;
+///
+/// // The sequence is -10, -2, 6, 8, 4, 6, 8, 4, …
+/// assert_eq!(nth_state(-10, |x| (x + 5) % 6 + 3, 1), -2);
+/// assert_eq!(nth_state(-10, |x| (x + 5) % 6 + 3, 1_000_000_000_000), 4);
+/// ```
+pub fn nth_state<T, FS>(start: T, successor: FS, n: usize) -> T
+where
+    T: Clone + Eq + Hash,
+    FS: FnMut(T) -> T,
+{
+    nth_state_by_key(start, successor, T::clone, n)
+}
+
+/// Compute the state at index `n` of an eventually periodic sequence, where `start`
+/// is at index 0, by remembering the keys of the states already seen.
+///
+/// - `start` is the first state of the sequence.
+/// - `successor` computes the next state of the sequence.
+/// - `key` extracts the part of a state which determines the rest of the sequence.
+///   Two states with the same key are considered identical.
+/// - `n` is the index of the requested state.
+///
+/// States are computed and remembered until a cycle is found or the state at index `n`
+/// is reached. When a cycle is found, the state at index `n` is the one located at the
+/// same position in the cycle. Note that parts of a state not included in the key are
+/// not extrapolated: they are the ones of the equivalent state in the first occurrence of
+/// the cycle.
+///
+/// # Example
+///
+/// Rotating the rows of a grid, where only the grid content matters and not the
+/// number of rotations done:
+///
+/// ```
+/// use pathfinding::prelude::nth_state_by_key;
+///
+/// let rotate = |(mut rows, count): (Vec<u8>, usize)| {
+///     rows.rotate_left(1);
+///     (rows, count + 1)
+/// };
+/// let (rows, count) = nth_state_by_key(
+///     (vec![1, 2, 3], 0),
+///     rotate,
+///     |(rows, _)| rows.clone(),
+///     1_000_000_000_000,
+/// );
+/// assert_eq!(rows, vec![2, 3, 1]);
+/// assert_eq!(count, 1);
+/// ```
+pub fn nth_state_by_key<T, K, FS, FK>(start: T, successor: FS, key: FK, n: usize) -> T
+where
+    T: Clone,
+    K: Eq + Hash,
+    FS: FnMut(T) -> T,
+    FK: FnMut(&T) -> K,
+{
+    let (mut states, mu) = record_states(start, successor, key, n);
+    let index = match mu {
+        Some(mu) if n >= states.len() => mu + (n - mu) % (states.len() - mu),
+        _ => n,
+    };
+    states.swap_remove(index)
+}
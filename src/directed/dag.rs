@@ -0,0 +1,318 @@
+//! Compute shortest and longest paths in a directed acyclic graph, and
+//! perform a [critical path
+//! analysis](https://en.wikipedia.org/wiki/Critical_path_method) on a set
+//! of dependent tasks.
+
+use super::topological_sort::topological_sort;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+/// Determine all reachable nodes from a starting point in a directed acyclic
+/// graph as well as the minimum cost to reach them and a possible optimal
+/// parent node.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor. Costs may be negative.
+///
+/// The nodes are processed in topological order, so this function runs in linear
+/// time in the number of reachable nodes and edges.
+///
+/// The result is a map where every reachable node (not including `start`) is associated with
+/// an optimal parent node and a cost from the start node, as is the case with
+/// [`dijkstra_all`](super::dijkstra::dijkstra_all). The
+/// [`build_path`](super::dijkstra::build_path) function can be used to build a full path from
+/// the starting point to one of the reachable targets.
+///
+/// # Errors
+///
+/// If a cycle is found, `Err(n)` is returned with `n` being an arbitrary node involved in a cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{build_path, dag_shortest_paths};
+///
+/// let successors = |&n: &char| match n {
+///     'a' => vec![('b', 3), ('c', 5)],
+///     'b' => vec![('c', -4), ('d', 1)],
+///     'c' => vec![('d', 2)],
+///     _ => vec![],
+/// };
+/// let paths = dag_shortest_paths(&'a', successors).unwrap();
+/// assert_eq!(paths[&'d'], ('c', 1));
+/// assert_eq!(build_path(&'d', &paths), vec!['a', 'b', 'c', 'd']);
+/// ```
+pub fn dag_shortest_paths<N, C, FN, IN>(start: &N, successors: FN) -> Result<HashMap<N, (N, C)>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    dag_paths(start, successors, |new, old| new < old)
+}
+
+/// Determine all reachable nodes from a starting point in a directed acyclic
+/// graph as well as the maximum cost to reach them and a possible optimal
+/// parent node.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor. Costs may be negative.
+///
+/// The nodes are processed in topological order, so this function runs in linear
+/// time in the number of reachable nodes and edges.
+///
+/// The result is a map where every reachable node (not including `start`) is associated with
+/// an optimal parent node and the cost of the longest path from the start node. The
+/// [`build_path`](super::dijkstra::build_path) function can be used to build a full path from
+/// the starting point to one of the reachable targets.
+///
+/// # Errors
+///
+/// If a cycle is found, `Err(n)` is returned with `n` being an arbitrary node involved in a cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{build_path, dag_longest_paths};
+///
+/// let successors = |&n: &char| match n {
+///     'a' => vec![('b', 3), ('c', 5)],
+///     'b' => vec![('c', -4), ('d', 1)],
+///     'c' => vec![('d', 2)],
+///     _ => vec![],
+/// };
+/// let paths = dag_longest_paths(&'a', successors).unwrap();
+/// assert_eq!(paths[&'d'], ('c', 7));
+/// assert_eq!(build_path(&'d', &paths), vec!['a', 'c', 'd']);
+/// ```
+pub fn dag_longest_paths<N, C, FN, IN>(start: &N, successors: FN) -> Result<HashMap<N, (N, C)>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    dag_paths(start, successors, |new, old| new > old)
+}
+
+fn dag_paths<N, C, FN, IN, FB>(
+    start: &N,
+    successors: FN,
+    better: FB,
+) -> Result<HashMap<N, (N, C)>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FB: Fn(C, C) -> bool,
+{
+    let mut edges = FxHashMap::default();
+    let sorted = sort_recording_edges(
+        std::slice::from_ref(start),
+        successors,
+        |(s, _)| s.clone(),
+        &mut edges,
+    )?;
+    let mut parents: HashMap<N, (N, C)> = HashMap::with_capacity(sorted.len());
+    for node in sorted {
+        let cost = if node == *start {
+            Zero::zero()
+        } else {
+            parents[&node].1
+        };
+        for (successor, move_cost) in edges.remove(&node).unwrap_or_default() {
+            let new_cost = cost + move_cost;
+            if parents
+                .get(&successor)
+                .is_none_or(|&(_, old_cost)| better(new_cost, old_cost))
+            {
+                parents.insert(successor, (node.clone(), new_cost));
+            }
+        }
+    }
+    Ok(parents)
+}
+
+// Sort the graph topologically while recording the edges leaving every node into
+// `edges`, so that the successors of a given node are requested only once. `target`
+// returns the node an edge leads to.
+fn sort_recording_edges<N, E, FN, IN, FT>(
+    nodes: &[N],
+    mut successors: FN,
+    target: FT,
+    edges: &mut FxHashMap<N, Vec<E>>,
+) -> Result<Vec<N>, N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = E>,
+    FT: Fn(&E) -> N,
+{
+    topological_sort(nodes, |n| {
+        let succs = successors(n).into_iter().collect::<Vec<_>>();
+        let targets = succs.iter().map(&target).collect::<Vec<_>>();
+        edges.insert(n.clone(), succs);
+        targets
+    })
+}
+
+/// Timing information for a task, as computed by [`critical_path`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskTiming<C> {
+    /// The earliest time at which the task can start.
+    pub earliest_start: C,
+    /// The latest time at which the task can start without delaying the whole project.
+    pub latest_start: C,
+    /// The amount of time by which the task can be delayed without delaying the
+    /// whole project. Tasks with no slack are critical.
+    pub slack: C,
+}
+
+/// Result of a critical path analysis, as computed by [`critical_path`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CriticalPath<N, C>
+where
+    N: Eq + Hash,
+{
+    /// The minimum duration of the whole project.
+    pub duration: C,
+    /// The timing information for every task.
+    pub timings: HashMap<N, TaskTiming<C>>,
+    /// A chain of critical tasks, in execution order, whose total duration is the duration of
+    /// the whole project.
+    pub path: Vec<N>,
+}
+
+/// Perform a [critical path analysis](https://en.wikipedia.org/wiki/Critical_path_method) on
+/// a set of dependent tasks.
+///
+/// - `tasks` is a collection of tasks.
+/// - `duration` returns the (non-negative) duration of a given task.
+/// - `dependencies` returns the list of tasks which must be completed before a given task
+///   can start, including possibly tasks that were not present in `tasks`.
+///
+/// Every task starts as soon as possible, the project starting at time zero. The function
+/// returns the total duration of the project, the earliest and latest start times of every
+/// task along with its slack, and a critical path, that is a chain of tasks which cannot be
+/// delayed without delaying the whole project.
+///
+/// # Errors
+///
+/// If a dependency cycle is found, `Err(n)` is returned with `n` being an arbitrary task
+/// involved in a cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::critical_path;
+///
+/// // Building a house: the roof needs the walls, which need the foundations.
+/// // The plumbing also needs the foundations, and the painting needs both the
+/// // roof and the plumbing.
+/// let tasks = ["foundations", "walls", "roof", "plumbing", "painting"];
+/// let duration = |&t: &&str| match t {
+///     "foundations" => 4,
+///     "walls" => 5,
+///     "roof" => 3,
+///     "plumbing" => 6,
+///     _ => 2,
+/// };
+/// let dependencies = |&t: &&str| match t {
+///     "walls" | "plumbing" => vec!["foundations"],
+///     "roof" => vec!["walls"],
+///     "painting" => vec!["roof", "plumbing"],
+///     _ => vec![],
+/// };
+/// let analysis = critical_path(&tasks, duration, dependencies).unwrap();
+/// assert_eq!(analysis.duration, 14);
+/// assert_eq!(analysis.path, vec!["foundations", "walls", "roof", "painting"]);
+/// let plumbing = analysis.timings["plumbing"];
+/// assert_eq!((plumbing.earliest_start, plumbing.latest_start, plumbing.slack), (4, 6, 2));
+/// ```
+pub fn critical_path<N, C, FD, FN, IN>(
+    tasks: &[N],
+    mut duration: FD,
+    dependencies: FN,
+) -> Result<CriticalPath<N, C>, N>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+    FD: FnMut(&N) -> C,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    // Tasks are sorted so that a task comes before its dependencies.
+    let mut deps = FxHashMap::default();
+    let mut sorted = sort_recording_edges(tasks, dependencies, N::clone, &mut deps)?;
+    sorted.reverse();
+    let durations = sorted
+        .iter()
+        .map(|t| (t.clone(), duration(t)))
+        .collect::<FxHashMap<_, _>>();
+    // Forward pass: compute the earliest finish time of every task.
+    let mut earliest_finish = FxHashMap::default();
+    let mut total = C::zero();
+    for task in &sorted {
+        let start = deps[task]
+            .iter()
+            .map(|d| earliest_finish[d])
+            .max()
+            .unwrap_or_else(C::zero);
+        let finish = start + durations[task];
+        total = total.max(finish);
+        earliest_finish.insert(task.clone(), finish);
+    }
+    // Backward pass: compute the latest finish time of every task.
+    let mut latest_finish: FxHashMap<N, C> = FxHashMap::default();
+    for task in sorted.iter().rev() {
+        let finish = *latest_finish.entry(task.clone()).or_insert(total);
+        let start = finish - durations[task];
+        for d in &deps[task] {
+            latest_finish
+                .entry(d.clone())
+                .and_modify(|f| *f = (*f).min(start))
+                .or_insert(start);
+        }
+    }
+    let timings = sorted
+        .iter()
+        .map(|task| {
+            let earliest_start = earliest_finish[task] - durations[task];
+            let latest_start = latest_finish[task] - durations[task];
+            (
+                task.clone(),
+                TaskTiming {
+                    earliest_start,
+                    latest_start,
+                    slack: latest_start - earliest_start,
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    // Follow critical tasks backwards from a task finishing last.
+    let mut path = Vec::new();
+    let mut current = sorted
+        .iter()
+        .rev()
+        .find(|t| earliest_finish[*t] == total && timings[*t].slack.is_zero());
+    while let Some(task) = current {
+        path.push(task.clone());
+        let start = timings[task].earliest_start;
+        current = deps[task]
+            .iter()
+            .find(|d| earliest_finish[*d] == start && timings[*d].slack.is_zero());
+    }
+    path.reverse();
+    Ok(CriticalPath {
+        duration: total,
+        timings,
+        path,
+    })
+}
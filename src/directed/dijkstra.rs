@@ -1,6 +1,7 @@
-//! Compute a shortest path using the [Dijkstra search
+//! Compute a shortest path (or all shortest paths) using the [Dijkstra search
 //! algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
 
+use super::astar::{AstarSolution, astar_bag};
 use super::reverse_path;
 use crate::FxIndexMap;
 use indexmap::map::Entry::{Occupied, Vacant};
@@ -104,6 +105,95 @@ where
     })
 }
 
+/// Compute all shortest paths using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
+///
+/// Whereas `dijkstra` (non-deterministic-ally) returns a single shortest
+/// path, `dijkstra_bag` returns all shortest paths (in a
+/// non-deterministic order).
+///
+/// The shortest paths starting from `start` up to a node for which `success` returns `true` are
+/// computed and returned in an iterator along with the cost (which, by definition, is the same for
+/// each shortest path), wrapped in a `Some`. If no paths are found, `None` is returned.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor. This cost must be non-negative.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+///
+/// A node will never be included twice in the path as determined by the `Eq` relationship.
+///
+/// Each path comprises both the start and an end node. Note that while every path shares the same
+/// start node, different paths may have different end nodes.
+///
+/// This is equivalent to calling [`astar_bag`] with a heuristic always returning zero.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_bag;
+///
+/// // 1 --> 2 --> 4
+/// //   --> 3 --> 4
+/// let (solutions, cost) = dijkstra_bag(
+///     &1,
+///     |&n| match n {
+///         1 => vec![(2, 1), (3, 1)],
+///         2 | 3 => vec![(4, 1)],
+///         _ => vec![],
+///     },
+///     |&n| n == 4,
+/// )
+/// .expect("no path found");
+/// assert_eq!(cost, 2);
+/// let mut solutions = solutions.collect::<Vec<_>>();
+/// solutions.sort();
+/// assert_eq!(solutions, vec![vec![1, 2, 4], vec![1, 3, 4]]);
+/// ```
+pub fn dijkstra_bag<N, C, FN, IN, FS>(
+    start: &N,
+    successors: FN,
+    success: FS,
+) -> Option<(AstarSolution<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar_bag(start, successors, |_| Zero::zero(), success)
+}
+
+/// Compute all shortest paths using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
+///
+/// Whereas `dijkstra` (non-deterministic-ally) returns a single shortest
+/// path, `dijkstra_bag` returns all shortest paths (in a
+/// non-deterministic order).
+///
+/// This is a utility function which collects the results of the `dijkstra_bag` function into a
+/// vector. Most of the time, it is more appropriate to use `dijkstra_bag` directly.
+///
+/// ### Warning
+///
+/// The number of results with the same value might be very large in some graphs. Use with caution.
+pub fn dijkstra_bag_collect<N, C, FN, IN, FS>(
+    start: &N,
+    successors: FN,
+    success: FS,
+) -> Option<(Vec<Vec<N>>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    dijkstra_bag(start, successors, success).map(|(solutions, cost)| (solutions.collect(), cost))
+}
+
 /// Determine all reachable nodes from a starting point as well as the
 /// minimum cost to reach them and a possible optimal parent node
 /// using the [Dijkstra search
@@ -117,7 +207,8 @@ where
 /// an optimal parent node and a cost from the start node.
 ///
 /// The [`build_path`] function can be used to build a full path from the starting point to one
-/// of the reachable targets.
+/// of the reachable targets. If all optimal parents are needed rather than one of them, use
+/// [`dijkstra_all_shortest_parents`] instead.
 ///
 /// # Example
 ///
@@ -152,6 +243,123 @@ where
     dijkstra_partial(start, successors, |_| false).0
 }
 
+/// Determine all reachable nodes from a starting point as well as the
+/// minimum cost to reach them and all the optimal parent nodes
+/// using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+///
+/// The result is a map where every reachable node (not including `start`) is associated with
+/// all its optimal parent nodes and a cost from the start node. Whereas [`dijkstra_all`]
+/// keeps only one optimal parent, this function keeps every node through which the minimal
+/// cost can be achieved, in no particular order.
+///
+/// If some moves have a zero cost, the parents map may contain loops.
+///
+/// # Example
+///
+/// We use a diamond-shaped graph in which node 4 can be reached from node 1
+/// through either node 2 or node 3 with the same cost.
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_all_shortest_parents;
+///
+/// fn successors(&n: &u32) -> Vec<(u32, usize)> {
+///   match n {
+///     1 => vec![(2, 1), (3, 1)],
+///     2 => vec![(4, 1), (5, 5)],
+///     3 => vec![(4, 1)],
+///     4 => vec![(5, 3)],
+///     _ => vec![],
+///   }
+/// }
+///
+/// let mut reachables = dijkstra_all_shortest_parents(&1, successors);
+/// reachables.values_mut().for_each(|(parents, _)| parents.sort_unstable());
+/// assert_eq!(reachables.len(), 4);
+/// assert_eq!(reachables[&2], (vec![1], 1));     // 1 -> 2
+/// assert_eq!(reachables[&3], (vec![1], 1));     // 1 -> 3
+/// assert_eq!(reachables[&4], (vec![2, 3], 2));  // 1 -> 2 -> 4 or 1 -> 3 -> 4
+/// assert_eq!(reachables[&5], (vec![4], 5));     // 1 -> (2 or 3) -> 4 -> 5
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn dijkstra_all_shortest_parents<N, C, FN, IN>(
+    start: &N,
+    mut successors: FN,
+) -> HashMap<N, (Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut to_see = BinaryHeap::new();
+    to_see.push(SmallestHolder {
+        cost: Zero::zero(),
+        index: 0,
+    });
+    let mut parents: FxIndexMap<N, (Vec<usize>, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (Vec::new(), Zero::zero()));
+    while let Some(SmallestHolder { cost, index }) = to_see.pop() {
+        let successors = {
+            let (node, &(_, c)) = parents.get_index(index).unwrap();
+            // We may have inserted a node several time into the binary heap if we found
+            // a better way to access it. Ensure that we are currently dealing with the
+            // best path and discard the others.
+            if cost > c {
+                continue;
+            }
+            successors(node)
+        };
+        for (successor, move_cost) in successors {
+            let new_cost = cost + move_cost;
+            let n;
+            match parents.entry(successor) {
+                Vacant(e) => {
+                    n = e.index();
+                    e.insert((vec![index], new_cost));
+                }
+                Occupied(mut e) => {
+                    if e.get().1 > new_cost {
+                        n = e.index();
+                        e.insert((vec![index], new_cost));
+                    } else {
+                        if e.get().1 == new_cost && !e.get().0.contains(&index) {
+                            // New parent with an identical cost, this is not
+                            // considered as an insertion.
+                            e.get_mut().0.push(index);
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            to_see.push(SmallestHolder {
+                cost: new_cost,
+                index: n,
+            });
+        }
+    }
+    parents
+        .iter()
+        .skip(1)
+        .map(|(n, (ps, c))| {
+            (
+                n.clone(),
+                (
+                    ps.iter()
+                        .map(|&p| parents.get_index(p).unwrap().0.clone()) // unwrap() cannot fail
+                        .collect(),
+                    *c,
+                ),
+            )
+        })
+        .collect()
+}
+
 /// Determine some reachable nodes from a starting point as well as the minimum cost to
 /// reach them and a possible optimal parent node
 /// using the [Dijkstra search algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
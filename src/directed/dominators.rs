@@ -0,0 +1,268 @@
+//! Compute the [dominators](https://en.wikipedia.org/wiki/Dominator_(graph_theory))
+//! of the nodes of a directed graph reachable from a root node.
+//!
+//! The iterative algorithm described by Cooper, Harvey and Kennedy in
+//! [A Simple, Fast Dominance Algorithm](https://www.cs.tufts.edu/comp/150FP/archive/keith-cooper/dom14.pdf)
+//! is used.
+
+use crate::FxIndexSet;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Dominance information about the nodes of a directed graph reachable from a
+/// root node, as computed by [`dominators`].
+///
+/// A node `a` dominates a node `b` if every path from the root to `b` goes
+/// through `a`. The immediate dominator of a node other than the root is its
+/// closest strict dominator, and is its parent in the dominator tree.
+#[derive(Clone, Debug)]
+pub struct Dominators<N> {
+    // Reachable nodes, in reverse postorder.
+    nodes: FxIndexSet<N>,
+    // Immediate dominator of every node, the root being its own immediate dominator.
+    idoms: Vec<usize>,
+    predecessors: Vec<Vec<usize>>,
+    children: Vec<Vec<usize>>,
+    // Preorder number of every node in the dominator tree, and the largest
+    // preorder number found in its subtree.
+    preorder: Vec<(usize, usize)>,
+}
+
+impl<N> Dominators<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// The root node.
+    #[must_use]
+    pub fn root(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    /// Check if a node is reachable from the root.
+    #[must_use]
+    pub fn is_reachable(&self, node: &N) -> bool {
+        self.nodes.contains(node)
+    }
+
+    /// Immediate dominator of a node, or `None` if the node is the root or is not
+    /// reachable from the root.
+    #[must_use]
+    pub fn immediate_dominator(&self, node: &N) -> Option<&N> {
+        match self.nodes.get_index_of(node) {
+            Some(0) | None => None,
+            Some(n) => Some(&self.nodes[self.idoms[n]]),
+        }
+    }
+
+    /// Iterate over the dominators of a node, starting from the node itself and
+    /// going up the dominator tree until the root. Nothing is returned if the node
+    /// is not reachable from the root.
+    pub fn dominators(&self, node: &N) -> impl Iterator<Item = &N> + '_ {
+        let mut current = self.nodes.get_index_of(node);
+        std::iter::from_fn(move || {
+            let n = current?;
+            current = (n != 0).then(|| self.idoms[n]);
+            Some(&self.nodes[n])
+        })
+    }
+
+    /// Check if `a` dominates `b`. Every reachable node dominates itself.
+    /// Return `false` if one of the nodes is not reachable from the root.
+    #[must_use]
+    pub fn dominates(&self, a: &N, b: &N) -> bool {
+        match (self.nodes.get_index_of(a), self.nodes.get_index_of(b)) {
+            (Some(a), Some(b)) => self.dominates_index(a, b),
+            _ => false,
+        }
+    }
+
+    /// Iterate over the children of a node in the dominator tree, that is the nodes
+    /// whose immediate dominator is `node`.
+    pub fn children(&self, node: &N) -> impl Iterator<Item = &N> + '_ {
+        self.nodes
+            .get_index_of(node)
+            .into_iter()
+            .flat_map(|n| self.children[n].iter().map(|&c| &self.nodes[c]))
+    }
+
+    /// Compute the dominance frontier of every reachable node, that is the set of nodes
+    /// `b` such that the node dominates a predecessor of `b` without strictly
+    /// dominating `b`.
+    ///
+    /// Every reachable node is present in the result, possibly with an empty frontier.
+    #[must_use]
+    pub fn dominance_frontiers(&self) -> HashMap<N, Vec<N>> {
+        let mut frontiers = vec![FxIndexSet::default(); self.nodes.len()];
+        for (b, preds) in self.predecessors.iter().enumerate() {
+            for &p in preds {
+                // Walk up the dominator tree from the predecessor until a strict
+                // dominator of `b` is found. The root may belong to its own frontier.
+                let mut runner = p;
+                while runner == b || !self.dominates_index(runner, b) {
+                    frontiers[runner].insert(b);
+                    if runner == 0 {
+                        break;
+                    }
+                    runner = self.idoms[runner];
+                }
+            }
+        }
+        self.nodes
+            .iter()
+            .zip(frontiers)
+            .map(|(n, frontier)| {
+                let frontier = frontier.into_iter().map(|f| self.nodes[f].clone());
+                (n.clone(), frontier.collect())
+            })
+            .collect()
+    }
+
+    fn dominates_index(&self, a: usize, b: usize) -> bool {
+        let ((a_pre, a_last), (b_pre, _)) = (self.preorder[a], self.preorder[b]);
+        a_pre <= b_pre && b_pre <= a_last
+    }
+}
+
+/// Compute the dominators of the nodes reachable from a root node.
+///
+/// - `root` is the entry node of the graph.
+/// - `successors` returns a list of successors for a given node.
+///
+/// The graph is explored using an explicit stack so that deep graphs can be handled
+/// without risking a stack overflow. The resulting [`Dominators`] structure can be used to navigate the
+/// dominator tree, to check dominance between two nodes in constant time, or to
+/// compute the dominance frontiers.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dominators;
+///
+/// // A control-flow graph with a conditional followed by a loop:
+/// // entry -> then | else -> join -> loop -> join | exit
+/// let dom = dominators(&"entry", |&n| match n {
+///     "entry" => vec!["then", "else"],
+///     "then" | "else" | "loop" => vec!["join"],
+///     "join" => vec!["loop", "exit"],
+///     _ => vec![],
+/// });
+/// assert_eq!(dom.immediate_dominator(&"join"), Some(&"entry"));
+/// assert_eq!(dom.immediate_dominator(&"exit"), Some(&"join"));
+/// assert!(dom.dominates(&"join", &"loop"));
+/// assert!(!dom.dominates(&"then", &"join"));
+/// assert_eq!(dom.dominators(&"loop").copied().collect::<Vec<_>>(), vec!["loop", "join", "entry"]);
+/// let frontiers = dom.dominance_frontiers();
+/// assert_eq!(frontiers["then"], vec!["join"]);
+/// assert_eq!(frontiers["loop"], vec!["join"]);
+/// assert_eq!(frontiers["join"], vec!["join"]);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn dominators<N, FN, IN>(root: &N, mut successors: FN) -> Dominators<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    // Explore the graph depth-first to compute a postorder of the reachable nodes.
+    let mut discovered = FxIndexSet::default();
+    discovered.insert(root.clone());
+    let mut edges = vec![Vec::new()];
+    let mut postorder = Vec::new();
+    let mut stack = vec![(0, successors(root).into_iter())];
+    while let Some((node, iter)) = stack.last_mut() {
+        let node = *node;
+        if let Some(successor) = iter.next() {
+            let (index, added) = discovered.insert_full(successor);
+            edges[node].push(index);
+            if added {
+                edges.push(Vec::new());
+                let iter = successors(discovered.get_index(index).unwrap()).into_iter(); // Cannot fail
+                stack.push((index, iter));
+            }
+        } else {
+            stack.pop();
+            postorder.push(node);
+        }
+    }
+    // Renumber the nodes in reverse postorder, so that the root gets 0 and every
+    // node gets a larger number than its immediate dominator.
+    let len = postorder.len();
+    let mut rank = vec![0; len];
+    for (i, &n) in postorder.iter().enumerate() {
+        rank[n] = len - 1 - i;
+    }
+    let mut predecessors = vec![Vec::new(); len];
+    for (n, succs) in edges.iter().enumerate() {
+        for &s in succs {
+            predecessors[rank[s]].push(rank[n]);
+        }
+    }
+    let mut discovered = discovered.into_iter().map(Some).collect::<Vec<_>>();
+    let nodes = postorder
+        .iter()
+        .rev()
+        .map(|&n| discovered[n].take().unwrap()) // Cannot fail
+        .collect::<FxIndexSet<_>>();
+    // Iterate until a fixed point is reached.
+    let mut idoms = vec![usize::MAX; len];
+    idoms[0] = 0;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for b in 1..len {
+            let mut processed = predecessors[b]
+                .iter()
+                .copied()
+                .filter(|&p| idoms[p] != usize::MAX);
+            // The node parent in the depth-first search precedes it in reverse postorder.
+            let first = processed.next().unwrap(); // Cannot fail
+            let idom = processed.fold(first, |a, p| intersect(&idoms, a, p));
+            if idoms[b] != idom {
+                idoms[b] = idom;
+                changed = true;
+            }
+        }
+    }
+    // Build the dominator tree and number it in preorder.
+    let mut children = vec![Vec::new(); len];
+    for b in 1..len {
+        children[idoms[b]].push(b);
+    }
+    let mut preorder = vec![(0, 0); len];
+    let mut counter = 0;
+    let mut stack = vec![(0, 0)];
+    while let Some((node, next)) = stack.last_mut() {
+        let node = *node;
+        if *next == 0 {
+            preorder[node].0 = counter;
+            counter += 1;
+        }
+        if let Some(&child) = children[node].get(*next) {
+            *next += 1;
+            stack.push((child, 0));
+        } else {
+            preorder[node].1 = counter - 1;
+            stack.pop();
+        }
+    }
+    Dominators {
+        nodes,
+        idoms,
+        predecessors,
+        children,
+        preorder,
+    }
+}
+
+// Find the nearest common dominator of two nodes, given their reverse postorder numbers.
+fn intersect(idoms: &[usize], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while a > b {
+            a = idoms[a];
+        }
+        while b > a {
+            b = idoms[b];
+        }
+    }
+    a
+}
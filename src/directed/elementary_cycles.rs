@@ -0,0 +1,266 @@
+//! Enumerate the [elementary cycles](https://en.wikipedia.org/wiki/Cycle_(graph_theory))
+//! of a directed graph, using [Johnson's
+//! algorithm](https://www.cs.tufts.edu/comp/150GA/homeworks/hw1/Johnson%2075.PDF).
+
+use super::index_graph;
+use super::strongly_connected_components::strongly_connected_components;
+use crate::FxIndexSet;
+use rustc_hash::FxHashSet;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+
+/// Iterator over the elementary cycles of a directed graph, as returned by
+/// [`elementary_cycles`] and [`elementary_cycles_with_length`].
+#[derive(Clone, Debug)]
+pub struct ElementaryCycles<N> {
+    nodes: FxIndexSet<N>,
+    successors: Vec<Vec<usize>>,
+    min_len: usize,
+    max_len: usize,
+    // Node whose cycles are being enumerated. Only cycles made of this node
+    // and nodes with a larger index are considered.
+    start: usize,
+    // Nodes belonging to the strongly connected component of `start` in the
+    // subgraph being considered.
+    component: Vec<bool>,
+    blocked: Vec<bool>,
+    blocking: Vec<FxHashSet<usize>>,
+    path: Vec<usize>,
+    // Nodes of the path, along with the index of their next successor to explore,
+    // and whether a cycle has been found through them.
+    stack: Vec<(usize, usize, bool)>,
+}
+
+impl<N> ElementaryCycles<N>
+where
+    N: Eq + Hash + Clone,
+{
+    fn new<FN, IN>(nodes: &[N], successors: FN, min_len: usize, max_len: usize) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+    {
+        let (discovered, mut edges) = index_graph(nodes, successors, |discovered, s| {
+            discovered.insert_full(s).0
+        });
+        // Parallel edges do not give distinct elementary cycles.
+        for succs in &mut edges {
+            let mut seen = FxHashSet::default();
+            succs.retain(|&s| seen.insert(s));
+        }
+        let len = discovered.len();
+        Self {
+            nodes: discovered,
+            successors: edges,
+            min_len: min_len.max(1),
+            max_len,
+            start: 0,
+            component: vec![false; len],
+            blocked: vec![false; len],
+            blocking: vec![FxHashSet::default(); len],
+            path: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    // In the subgraph made of the nodes numbered from `self.start` onwards, move
+    // `self.start` to the lowest-numbered node of a strongly connected component
+    // containing a cycle, and prepare the search for cycles going through it.
+    // Return `false` if there are no more cycles.
+    fn prepare(&mut self) -> bool {
+        let first = self.start;
+        let candidates = (first..self.nodes.len()).collect::<Vec<_>>();
+        let Some(component) = strongly_connected_components(&candidates, |&n| {
+            self.successors[n]
+                .iter()
+                .copied()
+                .filter(|&s| s >= first)
+                .collect::<Vec<_>>()
+        })
+        .into_iter()
+        .filter(|c| c.len() > 1 || self.successors[c[0]].contains(&c[0]))
+        .min_by_key(|c| c.iter().min().copied()) else {
+            return false;
+        };
+        let start = component.iter().min().copied().unwrap(); // Cannot fail
+        self.start = start;
+        self.component.fill(false);
+        for &n in &component {
+            self.component[n] = true;
+            self.blocked[n] = false;
+            self.blocking[n].clear();
+        }
+        self.blocked[start] = true;
+        self.path.push(start);
+        self.stack.push((start, 0, false));
+        true
+    }
+
+    fn unblock(&mut self, node: usize) {
+        let mut to_unblock = vec![node];
+        while let Some(n) = to_unblock.pop() {
+            if self.blocked[n] {
+                self.blocked[n] = false;
+                to_unblock.extend(self.blocking[n].drain());
+            }
+        }
+    }
+}
+
+impl<N> Iterator for ElementaryCycles<N>
+where
+    N: Eq + Hash + Clone,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((node, next, _)) = self.stack.last_mut() else {
+                if self.start >= self.nodes.len() || !self.prepare() {
+                    self.start = self.nodes.len();
+                    return None;
+                }
+                continue;
+            };
+            let node = *node;
+            if let Some(&successor) = self.successors[node].get(*next) {
+                *next += 1;
+                if !self.component[successor] {
+                    continue;
+                }
+                if successor == self.start {
+                    self.stack.last_mut().unwrap().2 = true; // Cannot fail
+                    if self.path.len() >= self.min_len && self.path.len() <= self.max_len {
+                        return Some(self.path.iter().map(|&n| self.nodes[n].clone()).collect());
+                    }
+                } else if !self.blocked[successor] && self.path.len() < self.max_len {
+                    self.blocked[successor] = true;
+                    self.path.push(successor);
+                    self.stack.push((successor, 0, false));
+                }
+                continue;
+            }
+            let (_, _, closed) = self.stack.pop().unwrap(); // Cannot fail
+            self.path.pop();
+            // When the cycles length is bounded, a node which could not be used to close
+            // a cycle may be usable later through a shorter path, so it is always
+            // unblocked.
+            if closed || self.max_len != usize::MAX {
+                self.unblock(node);
+                if let Some(parent) = self.stack.last_mut() {
+                    parent.2 |= closed;
+                }
+            } else {
+                for &successor in &self.successors[node] {
+                    if self.component[successor] {
+                        self.blocking[successor].insert(node);
+                    }
+                }
+            }
+            if self.stack.is_empty() {
+                self.start += 1;
+            }
+        }
+    }
+}
+
+impl<N> FusedIterator for ElementaryCycles<N> where N: Eq + Hash + Clone {}
+
+/// Enumerate all the elementary cycles of a directed graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// An elementary cycle is a closed path in which no node appears twice. Every cycle
+/// is returned exactly once as the list of its nodes, without repeating the first one:
+/// every node has the next one as a successor, and the last node has the first one as
+/// a successor. A node with a loop onto itself is a cycle of length 1.
+///
+/// Nodes are numbered in the order of `nodes` first, then in the order in which they are
+/// discovered through `successors`. Every cycle starts with its lowest-numbered node, and
+/// cycles are grouped by their first node in increasing order.
+///
+/// The graph is explored upfront, and the successors of a given node are requested only
+/// once. Cycles are then computed lazily: as in Johnson's algorithm, the strongly connected
+/// components of the remaining subgraph are computed once for every node starting some
+/// cycles, so that the delay between two consecutive cycles is linear in the size of
+/// the graph.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::elementary_cycles;
+///
+/// // 1 <-> 2 -> 3 -> 1, 3 -> 3
+/// let cycles = elementary_cycles(&[1], |&n| match n {
+///     1 => vec![2],
+///     2 => vec![1, 3],
+///     _ => vec![1, 3],
+/// })
+/// .collect::<Vec<_>>();
+/// assert_eq!(cycles, vec![vec![1, 2], vec![1, 2, 3], vec![3]]);
+/// ```
+pub fn elementary_cycles<N, FN, IN>(nodes: &[N], successors: FN) -> ElementaryCycles<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    ElementaryCycles::new(nodes, successors, 1, usize::MAX)
+}
+
+/// Enumerate the elementary cycles of a directed graph whose length (number of nodes)
+/// falls within the given range.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+/// - `lengths` is the range of acceptable cycle lengths.
+///
+/// This function works like [`elementary_cycles`], but only returns cycles with an
+/// acceptable length. When an upper bound is given, paths longer than this bound are
+/// not explored. As cycles which are too short or too long are skipped, the delay
+/// between two consecutive cycles is no longer guaranteed to be linear in the size of
+/// the graph when any bound is given.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::elementary_cycles_with_length;
+///
+/// // 1 <-> 2 -> 3 -> 1, 3 -> 3
+/// let successors = |&n: &u32| match n {
+///     1 => vec![2],
+///     2 => vec![1, 3],
+///     _ => vec![1, 3],
+/// };
+/// let cycles = elementary_cycles_with_length(&[1], successors, 2..).collect::<Vec<_>>();
+/// assert_eq!(cycles, vec![vec![1, 2], vec![1, 2, 3]]);
+/// let cycles = elementary_cycles_with_length(&[1], successors, ..=2).collect::<Vec<_>>();
+/// assert_eq!(cycles, vec![vec![1, 2], vec![3]]);
+/// ```
+pub fn elementary_cycles_with_length<N, FN, IN, R>(
+    nodes: &[N],
+    successors: FN,
+    lengths: R,
+) -> ElementaryCycles<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    R: RangeBounds<usize>,
+{
+    let min_len = match lengths.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.saturating_add(1),
+        Bound::Unbounded => 1,
+    };
+    let max_len = match lengths.end_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.saturating_sub(1),
+        Bound::Unbounded => usize::MAX,
+    };
+    ElementaryCycles::new(nodes, successors, min_len, max_len)
+}
@@ -0,0 +1,191 @@
+//! Find an [Eulerian trail or circuit](https://en.wikipedia.org/wiki/Eulerian_path)
+//! in a directed graph using Hierholzer's algorithm.
+
+use super::index_graph;
+use std::hash::Hash;
+use thiserror::Error;
+
+/// Reason why no Eulerian trail or circuit exists.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum EulerianError<N> {
+    /// The degree of this node is incompatible with the requested trail or circuit: for
+    /// a circuit, its in-degree differs from its out-degree (or its degree is odd in an
+    /// undirected graph), and for a trail, it is one more node whose degrees are unbalanced
+    /// than what can be accepted at the trail ends.
+    #[error("node degrees are unbalanced")]
+    Unbalanced(N),
+    /// The edges are not all connected together: this node has edges which cannot be
+    /// reached from the start of the trail.
+    #[error("edges are not all connected")]
+    Disconnected(N),
+}
+
+fn eulerian<N, FN, IN>(
+    nodes: &[N],
+    successors: FN,
+    circuit: bool,
+) -> Result<Vec<N>, EulerianError<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (nodes, edges) = index_graph(nodes, successors, |discovered, s| {
+        discovered.insert_full(s).0
+    });
+    let mut balances = edges
+        .iter()
+        .map(|succs| succs.len().cast_signed())
+        .collect::<Vec<_>>();
+    for &s in edges.iter().flatten() {
+        balances[s] -= 1;
+    }
+    let mut start = None;
+    let mut has_end = false;
+    for (n, &balance) in balances.iter().enumerate() {
+        let ok = match balance {
+            0 => true,
+            1 if !circuit && start.is_none() => {
+                start = Some(n);
+                true
+            }
+            -1 if !circuit && !has_end => {
+                has_end = true;
+                true
+            }
+            _ => false,
+        };
+        if !ok {
+            return Err(EulerianError::Unbalanced(nodes[n].clone()));
+        }
+    }
+    let Some(start) = start.or_else(|| edges.iter().position(|succs| !succs.is_empty())) else {
+        return Ok(Vec::new());
+    };
+    let mut next = vec![0; nodes.len()];
+    let mut stack = vec![start];
+    let mut trail = Vec::new();
+    while let Some(&node) = stack.last() {
+        if let Some(&successor) = edges[node].get(next[node]) {
+            next[node] += 1;
+            stack.push(successor);
+        } else {
+            trail.push(node);
+            stack.pop();
+        }
+    }
+    if let Some(n) = (0..nodes.len()).find(|&n| next[n] < edges[n].len()) {
+        return Err(EulerianError::Disconnected(nodes[n].clone()));
+    }
+    Ok(trail.into_iter().rev().map(|n| nodes[n].clone()).collect())
+}
+
+/// Find an Eulerian trail in a directed graph, that is a walk which uses every edge
+/// exactly once.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`. A successor appearing several times
+///   denotes parallel edges.
+///
+/// An Eulerian trail exists if the edges are all connected together, and if every node
+/// has the same in-degree and out-degree, except possibly for a start node with one more
+/// outgoing edge and an end node with one more incoming edge. If every node is balanced,
+/// the trail is a circuit which starts with the first node having edges, in the order of
+/// `nodes` first, then in the order in which they are discovered through `successors`.
+///
+/// The trail is returned as the list of visited nodes, starting with the first one and
+/// ending with the last one, so it contains one more node than the number of edges. An
+/// empty list is returned if the graph has no edges. The trail is computed in linear time.
+///
+/// # Errors
+///
+/// - [`EulerianError::Unbalanced`] is returned with a node whose degrees make an Eulerian
+///   trail impossible.
+/// - [`EulerianError::Disconnected`] is returned with a node whose edges cannot be reached
+///   from the start of the trail.
+///
+/// # Example
+///
+/// Reconstruct a sequence from all its overlapping fragments of length 3, using the
+/// de Bruijn graph whose nodes are the fragment prefixes and suffixes of length 2.
+///
+/// ```
+/// use pathfinding::prelude::eulerian_path;
+///
+/// let fragments = ["ATG", "TGG", "GGC", "GCG", "CGT", "GTG", "TGC"];
+/// let trail = eulerian_path(&["AT"], |&node| {
+///     fragments
+///         .iter()
+///         .filter(|f| f.starts_with(node))
+///         .map(|f| &f[1..])
+///         .collect::<Vec<_>>()
+/// })
+/// .unwrap();
+/// let sequence = trail.iter().skip(1).fold(trail[0].to_string(), |s, n| s + &n[1..]);
+/// assert_eq!(sequence, "ATGGCGTGC");
+/// ```
+pub fn eulerian_path<N, FN, IN>(nodes: &[N], successors: FN) -> Result<Vec<N>, EulerianError<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    eulerian(nodes, successors, false)
+}
+
+/// Find an Eulerian circuit in a directed graph, that is a closed walk which uses every
+/// edge exactly once.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`. A successor appearing several times
+///   denotes parallel edges.
+///
+/// An Eulerian circuit exists if the edges are all connected together, and if every node
+/// has the same in-degree and out-degree. The circuit starts with the first node having
+/// edges, in the order of `nodes` first, then in the order in which they are discovered
+/// through `successors`.
+///
+/// The circuit is returned as the list of visited nodes, where the last node is the same as
+/// the first one, so it contains one more node than the number of edges. An empty list is
+/// returned if the graph has no edges. The circuit is computed in linear time.
+///
+/// # Errors
+///
+/// - [`EulerianError::Unbalanced`] is returned with a node whose in-degree differs
+///   from its out-degree.
+/// - [`EulerianError::Disconnected`] is returned with a node whose edges cannot be reached
+///   from the start of the circuit.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{eulerian_circuit, EulerianError};
+///
+/// // 1 -> 2 -> 3 -> 1 -> 3 -> 4 -> 1
+/// let successors = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![3],
+///     3 => vec![1, 4],
+///     _ => vec![1],
+/// };
+/// let circuit = eulerian_circuit(&[1], successors).unwrap();
+/// assert_eq!(circuit, vec![1, 2, 3, 1, 3, 4, 1]);
+///
+/// // 1 -> 2 -> 3, 1 -> 3
+/// let successors = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![3],
+///     _ => vec![],
+/// };
+/// assert_eq!(eulerian_circuit(&[1], successors), Err(EulerianError::Unbalanced(1)));
+/// ```
+pub fn eulerian_circuit<N, FN, IN>(nodes: &[N], successors: FN) -> Result<Vec<N>, EulerianError<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    eulerian(nodes, successors, true)
+}
@@ -0,0 +1,130 @@
+//! Decompose a function over a finite domain into cycles and the trees feeding
+//! into them, as in the [rho shape](https://en.wikipedia.org/wiki/Cycle_detection)
+//! of the sequences obtained by iterating the function.
+
+use super::index_graph;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Position of an element in a [`FunctionalGraph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElementInfo<N> {
+    /// Number of applications of the function needed to reach a cycle, 0 if the
+    /// element belongs to a cycle.
+    pub tail_length: usize,
+    /// First element of a cycle reached when iterating the function from this
+    /// element, which is the element itself if it belongs to a cycle.
+    pub cycle_entry: N,
+    /// Index of the cycle reached, in [`FunctionalGraph::cycles`].
+    pub cycle: usize,
+    /// Elements not belonging to a cycle whose image is this element, that is the
+    /// children of this element in the tree rooted at its cycle entry.
+    pub children: Vec<N>,
+}
+
+/// Decomposition of a function over a finite domain, as computed by [`functional_graph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionalGraph<N>
+where
+    N: Eq + Hash,
+{
+    /// The cycles of the function. Within a cycle, the image of an element is the next one,
+    /// and the image of the last element is the first one.
+    pub cycles: Vec<Vec<N>>,
+    /// The position of every element of the domain.
+    pub elements: HashMap<N, ElementInfo<N>>,
+}
+
+/// Decompose a function over a finite domain into its cycles and the trees feeding into them.
+///
+/// - `domain` is a collection of elements.
+/// - `successor` returns the image of an element, which is added to the domain if it was
+///   not present in `domain`.
+///
+/// Iterating the function from any element eventually reaches a cycle, possibly after
+/// a tail of elements not belonging to any cycle. This function computes every cycle, and
+/// for every element its tail length, its cycle entry, and the elements of its tree which
+/// map onto it. It runs in linear time in the size of the domain.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::functional_graph;
+///
+/// // Doubling modulo 12: 1 -> 2 -> 4 -> 8 -> 4, 3 -> 6 -> 0 -> 0, 9 -> 6
+/// let graph = functional_graph(&[1, 3, 9], |&x| x * 2 % 12);
+/// assert_eq!(graph.cycles, vec![vec![4, 8], vec![0]]);
+/// let one = &graph.elements[&1];
+/// assert_eq!((one.tail_length, one.cycle_entry, one.cycle), (2, 4, 0));
+/// assert_eq!(graph.elements[&3].tail_length, 2);
+/// assert_eq!(graph.elements[&6].children, vec![3, 9]);
+/// assert_eq!(graph.elements[&0].children, vec![6]);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn functional_graph<N, FS>(domain: &[N], mut successor: FS) -> FunctionalGraph<N>
+where
+    N: Eq + Hash + Clone,
+    FS: FnMut(&N) -> N,
+{
+    let (nodes, edges) = index_graph(
+        domain,
+        |n| std::iter::once(successor(n)),
+        |nodes, image| nodes.insert_full(image).0,
+    );
+    let images = edges.into_iter().flatten().collect::<Vec<_>>();
+    let len = nodes.len();
+    // Tail length, cycle entry and cycle index of every element, once known.
+    let mut infos: Vec<Option<(usize, usize, usize)>> = vec![None; len];
+    let mut on_path = vec![false; len];
+    let mut cycles = Vec::new();
+    let mut path = Vec::new();
+    for start in 0..len {
+        let mut current = start;
+        while infos[current].is_none() && !on_path[current] {
+            on_path[current] = true;
+            path.push(current);
+            current = images[current];
+        }
+        if infos[current].is_none() {
+            // The path went back onto itself: its end is a new cycle.
+            let position = path.iter().rposition(|&n| n == current).unwrap(); // Cannot fail
+            let cycle = path.split_off(position);
+            for &n in &cycle {
+                infos[n] = Some((0, n, cycles.len()));
+                on_path[n] = false;
+            }
+            cycles.push(cycle);
+        }
+        while let Some(n) = path.pop() {
+            let (tail, entry, cycle) = infos[images[n]].unwrap(); // Cannot fail
+            infos[n] = Some((tail + 1, entry, cycle));
+            on_path[n] = false;
+        }
+    }
+    let mut children = vec![Vec::new(); len];
+    for (n, info) in infos.iter().enumerate() {
+        if info.is_some_and(|(tail, _, _)| tail > 0) {
+            children[images[n]].push(nodes[n].clone());
+        }
+    }
+    let elements = infos
+        .into_iter()
+        .zip(children)
+        .enumerate()
+        .map(|(n, (info, children))| {
+            let (tail_length, entry, cycle) = info.unwrap(); // Cannot fail
+            let info = ElementInfo {
+                tail_length,
+                cycle_entry: nodes[entry].clone(),
+                cycle,
+                children,
+            };
+            (nodes[n].clone(), info)
+        })
+        .collect();
+    let cycles = cycles
+        .into_iter()
+        .map(|cycle| cycle.into_iter().map(|n| nodes[n].clone()).collect())
+        .collect();
+    FunctionalGraph { cycles, elements }
+}
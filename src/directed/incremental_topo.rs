@@ -0,0 +1,237 @@
+//! Maintain a topological order of a directed acyclic graph while edges are
+//! being inserted, using the [Pearce-Kelly
+//! algorithm](https://doi.org/10.1145/1187436.1210590).
+
+use crate::FxIndexSet;
+use rustc_hash::FxHashSet;
+use std::hash::Hash;
+
+/// A directed acyclic graph whose nodes are kept in topological order while edges
+/// are inserted one at a time.
+///
+/// When an edge is inserted, only the nodes located between both ends of the
+/// new edge in the current order are examined and possibly moved, which is much
+/// faster than computing a new topological order from scratch with
+/// [`topological_sort`](super::topological_sort::topological_sort) when the
+/// graph grows incrementally. An edge whose insertion would create a cycle is
+/// rejected, and the cycle is returned instead.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::IncrementalTopo;
+///
+/// let mut tasks = IncrementalTopo::new();
+/// assert_eq!(tasks.add_edge("compile", "link"), Ok(true));
+/// assert_eq!(tasks.add_edge("fetch", "compile"), Ok(true));
+/// assert_eq!(tasks.order().copied().collect::<Vec<_>>(), vec!["fetch", "compile", "link"]);
+///
+/// // Making "fetch" depend on "link" would create a cycle.
+/// assert_eq!(tasks.add_edge("link", "fetch"), Err(vec!["link", "fetch", "compile"]));
+/// assert!(!tasks.contains_edge(&"link", &"fetch"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IncrementalTopo<N> {
+    nodes: FxIndexSet<N>,
+    // Position of every node in the topological order.
+    positions: Vec<usize>,
+    // Node found at every position of the topological order.
+    order: Vec<usize>,
+    successors: Vec<FxIndexSet<usize>>,
+    predecessors: Vec<FxIndexSet<usize>>,
+}
+
+impl<N> Default for IncrementalTopo<N> {
+    fn default() -> Self {
+        Self {
+            nodes: FxIndexSet::default(),
+            positions: Vec::new(),
+            order: Vec::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+        }
+    }
+}
+
+impl<N> IncrementalTopo<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Create a new empty graph.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of nodes in the graph.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check if the graph contains no nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Add a node to the graph, at the end of the current topological order.
+    /// Return `true` if the node was not already present.
+    pub fn add_node(&mut self, node: N) -> bool {
+        self.insert_node(node).1
+    }
+
+    /// Check if a node belongs to the graph.
+    #[must_use]
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.nodes.contains(node)
+    }
+
+    /// Check if the graph contains an edge from `from` to `to`.
+    #[must_use]
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        match (self.nodes.get_index_of(from), self.nodes.get_index_of(to)) {
+            (Some(from), Some(to)) => self.successors[from].contains(&to),
+            _ => false,
+        }
+    }
+
+    /// Add an edge from `from` to `to`, adding the nodes to the graph if needed,
+    /// and update the topological order so that `from` comes before `to`.
+    ///
+    /// Return `Ok(true)` if the edge has been added, or `Ok(false)` if it was already
+    /// present in the graph.
+    ///
+    /// # Errors
+    ///
+    /// If the new edge would create a cycle, it is not added to the graph (its ends are
+    /// added as nodes nonetheless) and `Err(cycle)` is returned. `cycle` starts with
+    /// `from` followed by `to`, and contains the nodes of the cycle in order: every node
+    /// has the next one as a successor, and the last node has `from` as a successor.
+    /// If `from` and `to` are the same node, `cycle` only contains this node.
+    pub fn add_edge(&mut self, from: N, to: N) -> Result<bool, Vec<N>> {
+        let (from, _) = self.insert_node(from);
+        let (to, _) = self.insert_node(to);
+        if from == to {
+            return Err(vec![self.nodes[from].clone()]);
+        }
+        if self.successors[from].contains(&to) {
+            return Ok(false);
+        }
+        let (lower, upper) = (self.positions[to], self.positions[from]);
+        if lower < upper {
+            let forward = self.forward(to, from).map_err(|cycle| {
+                cycle
+                    .into_iter()
+                    .map(|n| self.nodes[n].clone())
+                    .collect::<Vec<_>>()
+            })?;
+            let backward = self.backward(from, lower);
+            self.reorder(backward, forward);
+        }
+        self.successors[from].insert(to);
+        self.predecessors[to].insert(from);
+        Ok(true)
+    }
+
+    /// Remove the edge from `from` to `to`. Return `true` if the edge was present.
+    /// The current topological order remains valid and is not modified.
+    pub fn remove_edge(&mut self, from: &N, to: &N) -> bool {
+        match (self.nodes.get_index_of(from), self.nodes.get_index_of(to)) {
+            (Some(from), Some(to)) if self.successors[from].shift_remove(&to) => {
+                self.predecessors[to].swap_remove(&from);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Iterate over the nodes of the graph in the current topological order.
+    #[must_use]
+    pub fn order(&self) -> impl ExactSizeIterator<Item = &N> + DoubleEndedIterator + '_ {
+        self.order.iter().map(|&n| &self.nodes[n])
+    }
+
+    /// Position of a node in the current topological order, or `None` if the
+    /// node does not belong to the graph.
+    #[must_use]
+    pub fn position(&self, node: &N) -> Option<usize> {
+        self.nodes.get_index_of(node).map(|n| self.positions[n])
+    }
+
+    /// Iterate over the direct successors of a node, in insertion order. Nothing is
+    /// returned if the node does not belong to the graph.
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = &N> + '_ {
+        self.nodes
+            .get_index_of(node)
+            .into_iter()
+            .flat_map(|n| self.successors[n].iter().map(|&s| &self.nodes[s]))
+    }
+
+    fn insert_node(&mut self, node: N) -> (usize, bool) {
+        let (index, added) = self.nodes.insert_full(node);
+        if added {
+            self.positions.push(self.order.len());
+            self.order.push(index);
+            self.successors.push(FxIndexSet::default());
+            self.predecessors.push(FxIndexSet::default());
+        }
+        (index, added)
+    }
+
+    // Collect the nodes reachable from `start` which are located before `target`
+    // in the current order. If `target` is reachable, return the path from `target`
+    // to itself instead.
+    fn forward(&self, start: usize, target: usize) -> Result<Vec<usize>, Vec<usize>> {
+        let upper = self.positions[target];
+        let mut visited = FxHashSet::default();
+        visited.insert(start);
+        let mut stack = vec![(start, 0)];
+        while let Some((node, next)) = stack.last_mut() {
+            let Some(&successor) = self.successors[*node].get_index(*next) else {
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            if successor == target {
+                return Err(std::iter::once(target)
+                    .chain(stack.iter().map(|&(n, _)| n))
+                    .collect());
+            }
+            if self.positions[successor] < upper && visited.insert(successor) {
+                stack.push((successor, 0));
+            }
+        }
+        Ok(visited.into_iter().collect())
+    }
+
+    // Collect the nodes from which `start` can be reached which are located after
+    // the `lower` position in the current order.
+    fn backward(&self, start: usize, lower: usize) -> Vec<usize> {
+        let mut visited = FxHashSet::default();
+        visited.insert(start);
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for &predecessor in &self.predecessors[node] {
+                if self.positions[predecessor] > lower && visited.insert(predecessor) {
+                    stack.push(predecessor);
+                }
+            }
+        }
+        visited.into_iter().collect()
+    }
+
+    // Move the `backward` nodes before the `forward` nodes, reusing the positions
+    // they occupy and keeping their relative order within each group.
+    fn reorder(&mut self, mut backward: Vec<usize>, mut forward: Vec<usize>) {
+        backward.sort_unstable_by_key(|&n| self.positions[n]);
+        forward.sort_unstable_by_key(|&n| self.positions[n]);
+        let nodes = backward.into_iter().chain(forward).collect::<Vec<_>>();
+        let mut slots = nodes.iter().map(|&n| self.positions[n]).collect::<Vec<_>>();
+        slots.sort_unstable();
+        for (node, slot) in nodes.into_iter().zip(slots) {
+            self.positions[node] = slot;
+            self.order[slot] = node;
+        }
+    }
+}
@@ -1,20 +1,30 @@
 //! Algorithms for directed graphs.
 
-use super::FxIndexMap;
+use super::{FxIndexMap, FxIndexSet};
 use std::hash::Hash;
 
 pub mod astar;
 pub mod bfs;
+pub mod chinese_postman;
 pub mod count_paths;
 pub mod cycle_detection;
+pub mod dag;
 pub mod dfs;
 pub mod dijkstra;
+pub mod dominators;
 pub mod edmonds_karp;
+pub mod elementary_cycles;
+pub mod eulerian;
 pub mod fringe;
+pub mod functional_graph;
 pub mod idastar;
 pub mod iddfs;
+pub mod incremental_topo;
 pub mod strongly_connected_components;
 pub mod topological_sort;
+pub mod transitive;
+pub mod weakly_connected_components;
+pub mod weighted_cycles;
 pub mod yen;
 
 fn reverse_path<N, V, F>(parents: &FxIndexMap<N, V>, mut parent: F, start: usize) -> Vec<N>
@@ -34,3 +44,33 @@ where
     // unfold iterator is not double-ended due to its iterative nature.
     path.into_iter().rev().cloned().collect()
 }
+
+/// Discover all the nodes reachable from `nodes` through `successors`, in the order of
+/// `nodes` first, then in the order in which they are discovered. Return those nodes
+/// along with the edges leaving every one of them, where `index_edge` replaces the
+/// target node of an edge by its index. The successors of a given node are requested
+/// only once.
+pub(crate) fn index_graph<N, E, T, FN, IN, FI>(
+    nodes: &[N],
+    mut successors: FN,
+    mut index_edge: FI,
+) -> (FxIndexSet<N>, Vec<Vec<T>>)
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = E>,
+    FI: FnMut(&mut FxIndexSet<N>, E) -> T,
+{
+    let mut discovered = nodes.iter().cloned().collect::<FxIndexSet<_>>();
+    let mut edges = Vec::with_capacity(discovered.len());
+    while let Some(node) = discovered.get_index(edges.len()) {
+        let succs = successors(node).into_iter().collect::<Vec<_>>();
+        edges.push(
+            succs
+                .into_iter()
+                .map(|e| index_edge(&mut discovered, e))
+                .collect(),
+        );
+    }
+    (discovered, edges)
+}
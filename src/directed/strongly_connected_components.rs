@@ -1,84 +1,158 @@
 //! Separate nodes of a directed graph into [strongly connected
 //! components](https://en.wikipedia.org/wiki/Strongly_connected_component).
 //!
-//! A [path-based strong component
-//! algorithm](https://en.wikipedia.org/wiki/Path-based_strong_component_algorithm)
-//! is used.
+//! An iterative version of [Tarjan's strongly connected components
+//! algorithm](https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm)
+//! is used, so that very deep graphs can be handled without risking a stack overflow.
 
-use std::collections::{HashMap, HashSet};
+use crate::FxIndexSet;
+use std::collections::HashMap;
 use std::hash::Hash;
+use std::iter::FusedIterator;
 
-struct Params<N, FN>
+/// Lazy iterator over the strongly connected components of a graph, as returned by
+/// [`strongly_connected_components_iter`].
+///
+/// Components are yielded as soon as they are complete, in reverse topological
+/// order: a component is always yielded after every component it has edges towards.
+pub struct StronglyConnectedComponents<N, FN, IN>
 where
-    N: Hash + Eq,
+    IN: IntoIterator<Item = N>,
 {
-    preorders: HashMap<N, Option<usize>>,
-    c: usize,
+    roots: std::vec::IntoIter<N>,
     successors: FN,
-    p: Vec<N>,
-    s: Vec<N>,
-    scc: Vec<Vec<N>>,
-    scca: HashSet<N>,
+    // Visited nodes, indexed by discovery order.
+    visited: FxIndexSet<N>,
+    lowlinks: Vec<usize>,
+    on_stack: Vec<bool>,
+    // Nodes whose component has not been yielded yet.
+    stack: Vec<usize>,
+    // Nodes being explored, along with their unexplored successors.
+    path: Vec<(usize, IN::IntoIter)>,
 }
 
-impl<N, FN, IN> Params<N, FN>
+impl<N, FN, IN> StronglyConnectedComponents<N, FN, IN>
 where
     N: Clone + Hash + Eq,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    fn new(nodes: &[N], successors: FN) -> Self {
+    fn new(roots: Vec<N>, successors: FN) -> Self {
         Self {
-            preorders: nodes
-                .iter()
-                .map(|n| (n.clone(), None))
-                .collect::<HashMap<N, Option<usize>>>(),
-            c: 0,
+            roots: roots.into_iter(),
             successors,
-            p: Vec::new(),
-            s: Vec::new(),
-            scc: Vec::new(),
-            scca: HashSet::new(),
+            visited: FxIndexSet::default(),
+            lowlinks: Vec::new(),
+            on_stack: Vec::new(),
+            stack: Vec::new(),
+            path: Vec::new(),
         }
     }
+
+    fn visit(&mut self, node: N) {
+        let (index, _) = self.visited.insert_full(node);
+        self.lowlinks.push(index);
+        self.on_stack.push(true);
+        self.stack.push(index);
+        let successors = (self.successors)(&self.visited[index]).into_iter();
+        self.path.push((index, successors));
+    }
 }
 
-fn recurse_onto<N, FN, IN>(v: &N, params: &mut Params<N, FN>)
+impl<N, FN, IN> Iterator for StronglyConnectedComponents<N, FN, IN>
 where
     N: Clone + Hash + Eq,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    params.preorders.insert(v.clone(), Some(params.c));
-    params.c += 1;
-    params.s.push(v.clone());
-    params.p.push(v.clone());
-    for w in (params.successors)(v) {
-        if !params.scca.contains(&w) {
-            if let Some(pw) = params.preorders.get(&w).and_then(|w| *w) {
-                while params.preorders[&params.p[params.p.len() - 1]].unwrap() > pw {
-                    params.p.pop();
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((v, successors)) = self.path.last_mut() else {
+                let root = self.roots.find(|r| !self.visited.contains(r))?;
+                self.visit(root);
+                continue;
+            };
+            let v = *v;
+            if let Some(w) = successors.next() {
+                match self.visited.get_index_of(&w) {
+                    // Nodes are indexed in discovery order.
+                    Some(w) if self.on_stack[w] => self.lowlinks[v] = self.lowlinks[v].min(w),
+                    Some(_) => (),
+                    None => self.visit(w),
                 }
-            } else {
-                recurse_onto(&w, params);
+                continue;
             }
-        }
-    }
-    if params.p[params.p.len() - 1] == *v {
-        params.p.pop();
-        let mut component = Vec::new();
-        while let Some(node) = params.s.pop() {
-            component.push(node.clone());
-            params.scca.insert(node.clone());
-            params.preorders.remove(&node);
-            if node == *v {
-                break;
+            self.path.pop();
+            if let Some(&(u, _)) = self.path.last() {
+                self.lowlinks[u] = self.lowlinks[u].min(self.lowlinks[v]);
+            }
+            if self.lowlinks[v] == v {
+                let start = self.stack.iter().rposition(|&n| n == v).unwrap(); // Cannot fail
+                let component = self
+                    .stack
+                    .drain(start..)
+                    .map(|n| {
+                        self.on_stack[n] = false;
+                        self.visited[n].clone()
+                    })
+                    .collect();
+                return Some(component);
             }
         }
-        params.scc.push(component);
     }
 }
 
+impl<N, FN, IN> FusedIterator for StronglyConnectedComponents<N, FN, IN>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+}
+
+/// Lazily partition all strongly connected components in a graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node.
+///
+/// The returned iterator yields the strongly connected components as soon as they
+/// are complete, in reverse topological order. The graph is only explored as far as
+/// needed to complete the next component.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::strongly_connected_components_iter;
+///
+/// // 1 <-> 2 -> 3 <-> 4
+/// let mut components = strongly_connected_components_iter(&[1], |&n| match n {
+///     1 => vec![2],
+///     2 => vec![1, 3],
+///     3 => vec![4],
+///     _ => vec![3],
+/// });
+/// let mut first = components.next().unwrap();
+/// first.sort_unstable();
+/// assert_eq!(first, vec![3, 4]);
+/// let mut second = components.next().unwrap();
+/// second.sort_unstable();
+/// assert_eq!(second, vec![1, 2]);
+/// assert_eq!(components.next(), None);
+/// ```
+pub fn strongly_connected_components_iter<N, FN, IN>(
+    nodes: &[N],
+    successors: FN,
+) -> StronglyConnectedComponents<N, FN, IN>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    StronglyConnectedComponents::new(nodes.to_vec(), successors)
+}
+
 /// Partition nodes reachable from a starting point into strongly connected components.
 ///
 /// - `start` is the node we want to explore the graph from.
@@ -92,9 +166,7 @@ where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut params = Params::new(&[], successors);
-    recurse_onto(start, &mut params);
-    params.scc
+    StronglyConnectedComponents::new(vec![start.clone()], successors).collect()
 }
 
 /// Compute the strongly connected component containing a given node.
@@ -129,9 +201,120 @@ where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut params = Params::new(nodes, successors);
-    while let Some(node) = params.preorders.keys().find(|_| true).cloned() {
-        recurse_onto(&node, &mut params);
-    }
-    params.scc
+    strongly_connected_components_iter(nodes, successors).collect()
+}
+
+/// Condensation of a directed graph, as computed by [`condensation`].
+///
+/// Every strongly connected component of the graph is contracted into a single node,
+/// which gives a directed acyclic graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Condensation<N>
+where
+    N: Eq + Hash,
+{
+    /// The strongly connected components, in topological order: edges only go from a
+    /// component to a component with a larger index.
+    pub components: Vec<Vec<N>>,
+    /// The index of the component every node belongs to.
+    pub membership: HashMap<N, usize>,
+    /// The successors of every component in the condensation, without duplicates.
+    pub edges: Vec<Vec<usize>>,
+}
+
+/// Compute the condensation of a directed graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// The function returns the strongly connected components of the graph in topological
+/// order, the component every node belongs to, and the edges between components. Edges
+/// between nodes of the same component are not represented. Successors of a given node
+/// are requested only once.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::condensation;
+///
+/// // 1 <-> 2 -> 3 <-> 4, 1 -> 5
+/// let condensation = condensation(&[1], |&n| match n {
+///     1 => vec![2, 5],
+///     2 => vec![1, 3],
+///     3 => vec![4],
+///     4 => vec![3],
+///     _ => vec![],
+/// });
+/// assert_eq!(condensation.components.len(), 3);
+/// let c1 = condensation.membership[&1];
+/// let c3 = condensation.membership[&4];
+/// let c5 = condensation.membership[&5];
+/// assert_eq!(c1, 0);
+/// assert_eq!(condensation.membership[&2], c1);
+/// assert_eq!(condensation.edges[c1].len(), 2);
+/// assert!(condensation.edges[c1].contains(&c3));
+/// assert!(condensation.edges[c3].is_empty());
+/// assert!(condensation.edges[c5].is_empty());
+/// ```
+pub fn condensation<N, FN, IN>(nodes: &[N], successors: FN) -> Condensation<N>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    condensation_with_successors(nodes, successors).0
+}
+
+// Compute the condensation of a directed graph, along with the successors
+// of every node as returned by `successors`.
+pub(crate) fn condensation_with_successors<N, FN, IN>(
+    nodes: &[N],
+    mut successors: FN,
+) -> (Condensation<N>, HashMap<N, Vec<N>>)
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    // Remember the edges while computing the components so that the
+    // successors of a given node are requested only once.
+    let mut node_edges = HashMap::new();
+    let mut components = strongly_connected_components(nodes, |n| {
+        let succs = successors(n).into_iter().collect::<Vec<_>>();
+        node_edges.insert(n.clone(), succs.clone());
+        succs
+    });
+    // Components are found in reverse topological order.
+    components.reverse();
+    let membership = components
+        .iter()
+        .enumerate()
+        .flat_map(|(c, nodes)| nodes.iter().map(move |n| (n.clone(), c)))
+        .collect::<HashMap<_, _>>();
+    // Component from which a given component was last reached, to avoid duplicate edges.
+    let mut seen = vec![usize::MAX; components.len()];
+    let edges = components
+        .iter()
+        .enumerate()
+        .map(|(c, nodes)| {
+            let mut succs = Vec::new();
+            for successor in nodes.iter().flat_map(|n| &node_edges[n]) {
+                let s = membership[successor];
+                if s != c && seen[s] != c {
+                    seen[s] = c;
+                    succs.push(s);
+                }
+            }
+            succs
+        })
+        .collect();
+    (
+        Condensation {
+            components,
+            membership,
+            edges,
+        },
+        node_edges,
+    )
 }
@@ -1,7 +1,11 @@
 //! Find a topological order in a directed graph if one exists.
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use super::index_graph;
+use crate::FxIndexSet;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::hash::Hash;
+use std::iter::FusedIterator;
 use std::mem;
 
 /// Find a topological order in a directed graph if one exists.
@@ -73,57 +77,92 @@ use std::mem;
 /// set.sort();
 /// assert_eq!(set, vec![7, 8, 9]);
 /// ```
-pub fn topological_sort<N, FN, IN>(roots: &[N], mut successors: FN) -> Result<Vec<N>, N>
+pub fn topological_sort<N, FN, IN>(roots: &[N], successors: FN) -> Result<Vec<N>, N>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut marked = HashSet::with_capacity(roots.len());
-    let mut temp = HashSet::new();
-    let mut sorted = VecDeque::with_capacity(roots.len());
-    let mut roots: HashSet<N> = roots.iter().cloned().collect::<HashSet<_>>();
-    while let Some(node) = roots.iter().next().cloned() {
-        temp.clear();
-        visit(
-            &node,
-            &mut successors,
-            &mut roots,
-            &mut marked,
-            &mut temp,
-            &mut sorted,
-        )?;
-    }
-    Ok(sorted.into_iter().collect())
+    topological_sort_or_cycle(roots, successors).map_err(|mut cycle| cycle.swap_remove(0))
 }
 
-fn visit<N, FN, IN>(
-    node: &N,
-    successors: &mut FN,
-    unmarked: &mut HashSet<N>,
-    marked: &mut HashSet<N>,
-    temp: &mut HashSet<N>,
-    sorted: &mut VecDeque<N>,
-) -> Result<(), N>
+/// Find a topological order in a directed graph if one exists, or a cycle otherwise.
+///
+/// - `roots` is a collection of nodes that ought to be explored.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `roots`.
+///
+/// This function works like [`topological_sort`], but returns a full cycle instead of
+/// a single node when the graph cannot be sorted. The graph is explored using an
+/// explicit stack, so arbitrarily deep graphs can be handled without risking a stack
+/// overflow.
+///
+/// # Errors
+///
+/// If a cycle is found, `Err(cycle)` is returned with `cycle` containing the nodes of the
+/// cycle in order: every node has the next one as a successor, and the last node has the
+/// first one as a successor. Each node appears only once in the cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::topological_sort_or_cycle;
+///
+/// // "app" depends on "web" which depends on "db" and "log", and "log"
+/// // also depends on "app".
+/// let dependencies = |&n: &&str| match n {
+///     "app" => vec!["web"],
+///     "web" => vec!["db", "log"],
+///     "log" => vec!["app"],
+///     _ => vec![],
+/// };
+///
+/// let cycle = topological_sort_or_cycle(&["app"], dependencies).unwrap_err();
+/// assert_eq!(cycle, vec!["app", "web", "log"]);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn topological_sort_or_cycle<N, FN, IN>(
+    roots: &[N],
+    mut successors: FN,
+) -> Result<Vec<N>, Vec<N>>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    unmarked.remove(node);
-    if marked.contains(node) {
-        return Ok(());
-    }
-    if temp.contains(node) {
-        return Err(node.clone());
-    }
-    temp.insert(node.clone());
-    for n in successors(node) {
-        visit(&n, successors, unmarked, marked, temp, sorted)?;
+    let mut marked = HashSet::with_capacity(roots.len());
+    let mut sorted = VecDeque::with_capacity(roots.len());
+    let mut roots: HashSet<N> = roots.iter().cloned().collect::<HashSet<_>>();
+    while let Some(root) = roots.iter().next().cloned() {
+        roots.remove(&root);
+        if marked.contains(&root) {
+            continue;
+        }
+        // Nodes on the current exploration path, in order, along with the
+        // successors remaining to explore for each of them.
+        let mut path = FxIndexSet::default();
+        let mut stack = vec![successors(&root).into_iter()];
+        path.insert(root);
+        while let Some(iter) = stack.last_mut() {
+            if let Some(node) = iter.next() {
+                roots.remove(&node);
+                if marked.contains(&node) {
+                    continue;
+                }
+                if let Some(index) = path.get_index_of(&node) {
+                    return Err(path.drain(index..).collect());
+                }
+                stack.push(successors(&node).into_iter());
+                path.insert(node);
+            } else {
+                stack.pop();
+                let node = path.pop().unwrap(); // Cannot fail
+                marked.insert(node.clone());
+                sorted.push_front(node);
+            }
+        }
     }
-    marked.insert(node.clone());
-    sorted.push_front(node.clone());
-    Ok(())
+    Ok(sorted.into_iter().collect())
 }
 
 /// Topologically sort a directed graph into groups of independent nodes.
@@ -213,3 +252,342 @@ where
     groups.push(prev_group);
     Ok(groups)
 }
+
+/// Topologically sort a directed graph into groups of independent nodes, or find a cycle.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node.
+///
+/// This function works like [`topological_sort_into_groups`], but returns a full cycle
+/// instead of the list of remaining nodes when the graph cannot be sorted. As is the case
+/// with [`topological_sort_into_groups`], the list of `nodes` must be exhaustive.
+///
+/// # Errors
+///
+/// A tuple `(groups, cycle)` containing a (possibly empty) partial list of groups, and
+/// the nodes of one of the cycles preventing the other nodes from being grouped. The
+/// nodes of the cycle are given in order: every node has the next one as a successor, and
+/// the last node has the first one as a successor.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::topological_sort_into_groups_or_cycle;
+///
+/// // 0 -> 1 -> 2 -> 3 -> 1, and 3 -> 4
+/// let succs: &[&[usize]] = &[&[1], &[2], &[3], &[1, 4], &[]];
+/// let (groups, mut cycle) =
+///     topological_sort_into_groups_or_cycle(&[0, 1, 2, 3, 4], |&n| succs[n].iter().copied())
+///         .unwrap_err();
+/// assert_eq!(groups, vec![vec![0]]);
+/// // Rotate the cycle so that it starts with its smallest node.
+/// let smallest = cycle.iter().position(|&n| n == 1).unwrap();
+/// cycle.rotate_left(smallest);
+/// assert_eq!(cycle, vec![1, 2, 3]);
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn topological_sort_into_groups_or_cycle<N, FN, IN>(
+    nodes: &[N],
+    mut successors: FN,
+) -> Result<Vec<Vec<N>>, (Vec<Vec<N>>, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    topological_sort_into_groups(nodes, &mut successors).map_err(|(groups, remaining)| {
+        // Every remaining node has at least one remaining predecessor, so the
+        // remaining nodes necessarily contain a cycle.
+        let remaining_set = remaining.iter().cloned().collect::<HashSet<_>>();
+        let Err(cycle) = topological_sort_or_cycle(&remaining, |n| {
+            successors(n)
+                .into_iter()
+                .filter(|s| remaining_set.contains(s))
+                .collect::<Vec<_>>()
+        }) else {
+            unreachable!("remaining nodes must contain a cycle");
+        };
+        (groups, cycle)
+    })
+}
+
+/// Find the topological order of a directed graph which favors nodes with the smallest key
+/// at every step.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+/// - `key` returns the priority key of a node. Amongst the nodes whose predecessors have
+///   all been placed already, the one with the smallest key is placed first. Nodes with
+///   the same key are placed in the order in which they appear in `nodes` or have
+///   been discovered.
+///
+/// Using [Kahn's algorithm](https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm)
+/// with a priority queue, this function returns the lexicographically smallest
+/// topological order of keys. Unlike [`topological_sort`], the result does not depend on
+/// the iteration order of any hash set, and is therefore reproducible. To favor nodes with
+/// the largest key instead, [`Reverse`] can be used.
+///
+/// This function runs in O(|V| log |V| + |E|).
+///
+/// # Errors
+///
+/// If there are cycles in the graph, a tuple `(sorted, remaining)` is returned, containing
+/// a (possibly empty) partial topological order, and a list of remaining nodes that could
+/// not be sorted due to cycles. In this case, the strongly connected set(s) can then be
+/// found using the
+/// [`strongly_connected_components`](super::strongly_connected_components::strongly_connected_components)
+/// function on the list of remaining nodes.
+///
+/// # Example
+///
+/// We sort migrations by their name, a migration being able to depend on other ones:
+///
+/// ```
+/// use pathfinding::prelude::topological_sort_by_key;
+/// use std::cmp::Reverse;
+///
+/// let migrations = ["users", "posts", "comments", "likes"];
+/// let dependents = |&m: &&str| match m {
+///     "users" => vec!["posts", "likes"],
+///     "posts" => vec!["comments", "likes"],
+///     _ => vec![],
+/// };
+///
+/// // Favor shortest names first.
+/// let sorted = topological_sort_by_key(&migrations, dependents, |m| m.len());
+/// assert_eq!(sorted, Ok(vec!["users", "posts", "likes", "comments"]));
+///
+/// // Favor longest names first.
+/// let sorted = topological_sort_by_key(&migrations, dependents, |m| Reverse(m.len()));
+/// assert_eq!(sorted, Ok(vec!["users", "posts", "comments", "likes"]));
+/// ```
+///
+/// [`Reverse`]: std::cmp::Reverse
+pub fn topological_sort_by_key<N, K, FN, IN, FK>(
+    nodes: &[N],
+    mut successors: FN,
+    mut key: FK,
+) -> Result<Vec<N>, (Vec<N>, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    K: Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FK: FnMut(&N) -> K,
+{
+    let (indices, succs, mut num_preds) = index_graph_with_predecessors(nodes, &mut successors);
+    let mut to_see = (0..indices.len())
+        .filter(|&i| num_preds[i] == 0)
+        .map(|i| Reverse((key(&indices[i]), i)))
+        .collect::<BinaryHeap<_>>();
+    let mut sorted = Vec::with_capacity(indices.len());
+    while let Some(Reverse((_, i))) = to_see.pop() {
+        sorted.push(i);
+        for &s in &succs[i] {
+            num_preds[s] -= 1;
+            if num_preds[s] == 0 {
+                to_see.push(Reverse((key(&indices[s]), s)));
+            }
+        }
+    }
+    if sorted.len() == indices.len() {
+        return Ok(sorted.into_iter().map(|i| indices[i].clone()).collect());
+    }
+    let remaining = (0..indices.len())
+        .filter(|&i| num_preds[i] > 0)
+        .map(|i| indices[i].clone())
+        .collect();
+    Err((
+        sorted.into_iter().map(|i| indices[i].clone()).collect(),
+        remaining,
+    ))
+}
+
+/// Find the lexicographically smallest topological order of a directed graph.
+///
+/// This is a shortcut for [`topological_sort_by_key`] using the nodes themselves
+/// as keys: amongst the nodes whose predecessors have all been placed already,
+/// the smallest one is always placed first.
+///
+/// # Errors
+///
+/// See [`topological_sort_by_key`].
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::topological_sort_lexicographic;
+///
+/// // Task 3 must be done before task 1, other tasks are independent.
+/// let sorted = topological_sort_lexicographic(&[1, 2, 3, 4], |&n| {
+///     if n == 3 { vec![1] } else { vec![] }
+/// });
+/// assert_eq!(sorted, Ok(vec![2, 3, 1, 4]));
+/// ```
+pub fn topological_sort_lexicographic<N, FN, IN>(
+    nodes: &[N],
+    successors: FN,
+) -> Result<Vec<N>, (Vec<N>, Vec<N>)>
+where
+    N: Eq + Hash + Clone + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    topological_sort_by_key(nodes, successors, Clone::clone)
+}
+
+/// Build an indexed representation of the graph containing `nodes` and all nodes
+/// reachable from them. Return the nodes, the successors indices of every node,
+/// and the number of predecessors of every node.
+fn index_graph_with_predecessors<N, FN, IN>(
+    nodes: &[N],
+    successors: FN,
+) -> (FxIndexSet<N>, Vec<Vec<usize>>, Vec<usize>)
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (indices, succs) = index_graph(nodes, successors, |indices, n| indices.insert_full(n).0);
+    let mut num_preds = vec![0usize; indices.len()];
+    for &s in succs.iter().flatten() {
+        num_preds[s] += 1;
+    }
+    (indices, succs, num_preds)
+}
+
+/// Enumerate all the topological orders of a directed acyclic graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// The orders are computed lazily, one at a time, by the returned iterator. Each order
+/// contains every node given in `nodes` or discovered through `successors`. Orders are
+/// produced in lexicographic order of the nodes positions in `nodes`, discovered nodes
+/// being considered as coming after the ones in `nodes`. The number
+/// of orders can grow exponentially with the number of nodes: a graph without any edge
+/// has n! topological orders.
+///
+/// If the graph contains a cycle, it has no topological order and the iterator is empty.
+/// [`topological_sort`] can be used to identify a node involved in the cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::topological_sorts;
+///
+/// // 1 -> 2 -> 4 and 1 -> 3 -> 4
+/// let orders = topological_sorts(&[1, 2, 3, 4], |&n| match n {
+///     1 => vec![2, 3],
+///     2 | 3 => vec![4],
+///     _ => vec![],
+/// })
+/// .collect::<Vec<_>>();
+/// assert_eq!(orders, vec![vec![1, 2, 3, 4], vec![1, 3, 2, 4]]);
+/// ```
+pub fn topological_sorts<N, FN, IN>(nodes: &[N], mut successors: FN) -> TopologicalSorts<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (indices, succs, num_preds) = index_graph_with_predecessors(nodes, &mut successors);
+    // Check that the graph is acyclic with Kahn's algorithm, so that the
+    // enumeration never reaches a dead end.
+    let mut remaining = num_preds.clone();
+    let mut to_see = (0..indices.len())
+        .filter(|&i| remaining[i] == 0)
+        .collect::<Vec<_>>();
+    let mut seen = 0;
+    while let Some(i) = to_see.pop() {
+        seen += 1;
+        for &s in &succs[i] {
+            remaining[s] -= 1;
+            if remaining[s] == 0 {
+                to_see.push(s);
+            }
+        }
+    }
+    TopologicalSorts {
+        placed: vec![false; indices.len()],
+        nodes: indices.into_iter().collect(),
+        succs,
+        num_preds,
+        order: Vec::new(),
+        cursors: vec![0],
+        started: false,
+        terminated: seen < remaining.len(),
+    }
+}
+
+/// Iterator structure created by the [`topological_sorts`] function.
+#[derive(Clone, Debug)]
+pub struct TopologicalSorts<N> {
+    nodes: Vec<N>,
+    succs: Vec<Vec<usize>>,
+    // Number of predecessors not placed yet for every node
+    num_preds: Vec<usize>,
+    placed: Vec<bool>,
+    // Current (partial) order
+    order: Vec<usize>,
+    // For every depth, the first node index that has not been tried yet
+    cursors: Vec<usize>,
+    started: bool,
+    terminated: bool,
+}
+
+impl<N> TopologicalSorts<N> {
+    fn place(&mut self, i: usize) {
+        self.placed[i] = true;
+        for &s in &self.succs[i] {
+            self.num_preds[s] -= 1;
+        }
+        self.order.push(i);
+        *self.cursors.last_mut().unwrap() = i + 1; // Cannot fail
+        self.cursors.push(0);
+    }
+
+    // Remove the last placed node. Return `false` if there are no nodes to remove.
+    fn backtrack(&mut self) -> bool {
+        let Some(i) = self.order.pop() else {
+            return false;
+        };
+        self.cursors.pop();
+        self.placed[i] = false;
+        for &s in &self.succs[i] {
+            self.num_preds[s] += 1;
+        }
+        true
+    }
+}
+
+impl<N: Clone> Iterator for TopologicalSorts<N> {
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.terminated {
+            return None;
+        }
+        if self.started && !self.backtrack() {
+            self.terminated = true;
+            return None;
+        }
+        self.started = true;
+        while self.order.len() < self.nodes.len() {
+            let cursor = *self.cursors.last().unwrap(); // Cannot fail
+            if let Some(i) =
+                (cursor..self.nodes.len()).find(|&i| !self.placed[i] && self.num_preds[i] == 0)
+            {
+                self.place(i);
+            } else if !self.backtrack() {
+                self.terminated = true;
+                return None;
+            }
+        }
+        Some(self.order.iter().map(|&i| self.nodes[i].clone()).collect())
+    }
+}
+
+impl<N: Clone> FusedIterator for TopologicalSorts<N> {}
@@ -0,0 +1,213 @@
+//! Compute the [transitive closure](https://en.wikipedia.org/wiki/Transitive_closure#In_graph_theory)
+//! and the [transitive reduction](https://en.wikipedia.org/wiki/Transitive_reduction)
+//! of a directed graph.
+
+use super::strongly_connected_components::{
+    Condensation, condensation, condensation_with_successors,
+};
+use rustc_hash::FxHashSet;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Reachability information between the nodes of a directed graph, as computed
+/// by [`transitive_closure`].
+///
+/// Reachability is stored as one bitset per strongly connected component of the
+/// graph, which makes [`reaches`](Self::reaches) queries run in constant time.
+#[derive(Clone, Debug)]
+pub struct TransitiveClosure<N> {
+    // Strongly connected component of every node.
+    components: HashMap<N, usize>,
+    // Number of 64-bit words used by every bitset.
+    words: usize,
+    // Bitsets of the components reachable from every component, one after the other.
+    bits: Vec<u64>,
+}
+
+impl<N> TransitiveClosure<N>
+where
+    N: Eq + Hash,
+{
+    /// Number of nodes known to the closure.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Check if the closure contains no nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Check if there exists a path from `from` to `to`. Every node reaches itself.
+    /// Return `false` if one of the nodes is unknown.
+    #[must_use]
+    pub fn reaches(&self, from: &N, to: &N) -> bool {
+        match (self.components.get(from), self.components.get(to)) {
+            (Some(&from), Some(&to)) => self.component_reaches(from, to),
+            _ => false,
+        }
+    }
+
+    /// Iterate over the nodes reachable from `node`, including `node` itself.
+    /// Nothing is returned if the node is unknown.
+    pub fn reachable<'a>(&'a self, node: &N) -> impl Iterator<Item = &'a N> + 'a {
+        let component = self.components.get(node).copied();
+        self.components
+            .iter()
+            .filter(move |&(_, &c)| component.is_some_and(|from| self.component_reaches(from, c)))
+            .map(|(n, _)| n)
+    }
+
+    fn component_reaches(&self, from: usize, to: usize) -> bool {
+        self.bits[from * self.words + to / 64] & (1 << (to % 64)) != 0
+    }
+}
+
+// Compute the bitsets of reachable components for every component of a condensation.
+fn reachability<N>(condensation: &Condensation<N>, words: usize) -> Vec<u64>
+where
+    N: Eq + Hash,
+{
+    let mut bits = vec![0; condensation.components.len() * words];
+    for (c, successors) in condensation.edges.iter().enumerate().rev() {
+        bits[c * words + c / 64] |= 1 << (c % 64);
+        // Successor components come later in topological order and have
+        // already been computed.
+        let (current, done) = bits.split_at_mut((c + 1) * words);
+        for &s in successors {
+            let done = &done[(s - c - 1) * words..];
+            for (b, &sb) in current[c * words..].iter_mut().zip(done) {
+                *b |= sb;
+            }
+        }
+    }
+    bits
+}
+
+/// Compute the transitive closure of a directed graph, that is the reachability
+/// relation between its nodes.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// The graph may contain cycles: the closure is computed on its
+/// [condensation](super::strongly_connected_components::condensation).
+/// The result uses one bit per pair of components.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::transitive_closure;
+///
+/// // 1 -> 2 -> 3 -> 2, 4 -> 1
+/// let closure = transitive_closure(&[1, 4], |&n| match n {
+///     1 | 3 => vec![2],
+///     2 => vec![3],
+///     4 => vec![1],
+///     _ => vec![],
+/// });
+/// assert_eq!(closure.len(), 4);
+/// assert!(closure.reaches(&4, &3));
+/// assert!(closure.reaches(&3, &2));
+/// assert!(!closure.reaches(&2, &1));
+/// assert!(closure.reaches(&1, &1));
+/// let mut from_1 = closure.reachable(&1).copied().collect::<Vec<_>>();
+/// from_1.sort_unstable();
+/// assert_eq!(from_1, vec![1, 2, 3]);
+/// ```
+pub fn transitive_closure<N, FN, IN>(nodes: &[N], successors: FN) -> TransitiveClosure<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let condensation = condensation(nodes, successors);
+    let words = condensation.components.len().div_ceil(64);
+    let bits = reachability(&condensation, words);
+    TransitiveClosure {
+        components: condensation.membership,
+        words,
+        bits,
+    }
+}
+
+/// Compute the transitive reduction of a directed acyclic graph, that is the graph
+/// with the fewest edges having the same reachability relation.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// The result associates every node of the graph with its successors in the transitive
+/// reduction, in the order in which they were returned by `successors`. An edge from `a`
+/// to `b` is kept only if there is no other path from `a` to `b`, and duplicate edges are
+/// removed.
+///
+/// # Errors
+///
+/// If a cycle is found, `Err(n)` is returned with `n` being an arbitrary node involved in a cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::transitive_reduction;
+///
+/// // "app" directly depends on "log", but also indirectly through "db".
+/// let reduction = transitive_reduction(&["app"], |&n| match n {
+///     "app" => vec!["db", "log"],
+///     "db" => vec!["log"],
+///     _ => vec![],
+/// })
+/// .unwrap();
+/// assert_eq!(reduction["app"], vec!["db"]);
+/// assert_eq!(reduction["db"], vec!["log"]);
+/// assert!(reduction["log"].is_empty());
+///
+/// assert!(transitive_reduction(&[1], |&n| vec![(n + 1) % 3]).is_err());
+/// ```
+pub fn transitive_reduction<N, FN, IN>(nodes: &[N], successors: FN) -> Result<HashMap<N, Vec<N>>, N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (mut condensation, edges) = condensation_with_successors(nodes, successors);
+    if let Some(component) = condensation.components.iter_mut().find(|c| c.len() > 1) {
+        return Err(component.swap_remove(0));
+    }
+    if let Some((node, _)) = edges.iter().find(|(n, s)| s.contains(n)) {
+        return Err(node.clone());
+    }
+    let words = condensation.components.len().div_ceil(64);
+    let bits = reachability(&condensation, words);
+    let mut reduction = HashMap::with_capacity(edges.len());
+    for (node, succs) in edges {
+        // Examine successors in topological order, and keep only those
+        // not reachable from an already kept one.
+        let mut candidates = succs
+            .iter()
+            .map(|s| condensation.membership[s])
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        let mut covered = vec![0u64; words];
+        let mut kept = FxHashSet::default();
+        for s in candidates {
+            if covered[s / 64] & (1 << (s % 64)) == 0 {
+                kept.insert(s);
+                for (b, &sb) in covered.iter_mut().zip(&bits[s * words..]) {
+                    *b |= sb;
+                }
+            }
+        }
+        let reduced = succs
+            .into_iter()
+            // Keep only the first occurrence of a given successor.
+            .filter(|s| kept.remove(&condensation.membership[s]))
+            .collect();
+        reduction.insert(node, reduced);
+    }
+    Ok(reduction)
+}
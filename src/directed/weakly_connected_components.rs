@@ -0,0 +1,92 @@
+//! Separate nodes of a directed graph into [weakly connected
+//! components](https://en.wikipedia.org/wiki/Connectivity_(graph_theory)#Connected_vertices_and_graphs).
+
+use crate::FxIndexSet;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::mem;
+
+fn find(parents: &mut [usize], mut node: usize) -> usize {
+    while parents[node] != node {
+        parents[node] = parents[parents[node]];
+        node = parents[node];
+    }
+    node
+}
+
+fn union(parents: &mut [usize], ranks: &mut [usize], a: usize, b: usize) {
+    let (mut a, mut b) = (find(parents, a), find(parents, b));
+    if a == b {
+        return;
+    }
+    if ranks[a] < ranks[b] {
+        mem::swap(&mut a, &mut b);
+    }
+    parents[b] = a;
+    if ranks[a] == ranks[b] {
+        ranks[a] += 1;
+    }
+}
+
+/// Partition all weakly connected components in a directed graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `nodes`.
+///
+/// Two nodes belong to the same weakly connected component if they are connected
+/// when the direction of the edges is ignored. Unlike with
+/// [`connected_components`](crate::undirected::connected_components::connected_components),
+/// `successors` does not need to be symmetric: it only has to be given in one direction.
+///
+/// The function returns a list of disjoint sets of nodes, in the order in which
+/// their first node was encountered.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::weakly_connected_components;
+/// use std::collections::HashSet;
+///
+/// // 1 -> 2 <- 3, 4 -> 5
+/// let components = weakly_connected_components(&[1, 3, 4], |&n| match n {
+///     1 | 3 => vec![2],
+///     4 => vec![5],
+///     _ => vec![],
+/// });
+/// assert_eq!(components, vec![HashSet::from([1, 2, 3]), HashSet::from([4, 5])]);
+/// ```
+pub fn weakly_connected_components<N, FN, IN>(nodes: &[N], mut successors: FN) -> Vec<HashSet<N>>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut discovered = nodes.iter().cloned().collect::<FxIndexSet<_>>();
+    let mut parents = (0..discovered.len()).collect::<Vec<_>>();
+    let mut ranks = vec![0; discovered.len()];
+    let mut i = 0;
+    while let Some(node) = discovered.get_index(i) {
+        for successor in successors(node) {
+            let (j, added) = discovered.insert_full(successor);
+            if added {
+                parents.push(j);
+                ranks.push(0);
+            }
+            union(&mut parents, &mut ranks, i, j);
+        }
+        i += 1;
+    }
+    // Number the components in the order in which they are first encountered.
+    let mut numbers = vec![usize::MAX; discovered.len()];
+    let mut components: Vec<HashSet<N>> = Vec::new();
+    for (i, node) in discovered.into_iter().enumerate() {
+        let root = find(&mut parents, i);
+        if numbers[root] == usize::MAX {
+            numbers[root] = components.len();
+            components.push(HashSet::new());
+        }
+        components[numbers[root]].insert(node);
+    }
+    components
+}
@@ -0,0 +1,233 @@
+//! Find cycles with a [minimum mean
+//! weight](https://en.wikipedia.org/wiki/Minimum_mean_weight_cycle) or with a negative
+//! total weight in a weighted directed graph.
+
+use super::index_graph;
+use num_traits::{FromPrimitive, Zero};
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+use std::ops::{Div, Mul, Sub};
+
+// Follow the parents of a node until a cycle is found, and return it in order.
+fn cycle_from_parents(parents: &[usize], mut node: usize) -> Vec<usize> {
+    // Going up `parents.len()` times guarantees that a node on the cycle is reached.
+    for _ in 0..parents.len() {
+        node = parents[node];
+    }
+    let mut cycle = vec![node];
+    let mut current = parents[node];
+    while current != node {
+        cycle.push(current);
+        current = parents[current];
+    }
+    cycle.reverse();
+    cycle
+}
+
+/// Find a cycle with a negative total weight in a weighted directed graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor, including possibly nodes that were not present in `nodes`.
+///
+/// The [Bellman-Ford algorithm](https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm)
+/// is run from every node at once, so a negative cycle is found wherever it is located in the
+/// graph. The successors of a given node are requested only once.
+///
+/// The function returns `None` if there is no negative cycle. Otherwise, it returns the nodes
+/// of a negative cycle in order, without repeating the first one: every node has the next one
+/// as a successor, and the last node has the first one as a successor.
+///
+/// # Example
+///
+/// Exchange rates are converted into costs using logarithms, so that a sequence
+/// of exchanges ending with a profit corresponds to a negative cycle.
+///
+/// ```
+/// use pathfinding::prelude::find_negative_cycle;
+///
+/// // Costs are -1000 × ln(rate), rounded.
+/// let rates = |&c: &&str| match c {
+///     "EUR" => vec![("USD", -90), ("GBP", 158)],
+///     "USD" => vec![("JPY", -5011)],
+///     "JPY" => vec![("EUR", 5094)],
+///     _ => vec![("EUR", -153)],
+/// };
+/// let mut cycle = find_negative_cycle(&["EUR"], rates).unwrap();
+/// let start = cycle.iter().position(|&c| c == "EUR").unwrap();
+/// cycle.rotate_left(start);
+/// assert_eq!(cycle, vec!["EUR", "USD", "JPY"]);
+/// ```
+pub fn find_negative_cycle<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (nodes, edges) = index_graph(nodes, successors, |discovered, (s, c)| {
+        (discovered.insert_full(s).0, c)
+    });
+    let len = nodes.len();
+    let mut costs = vec![C::zero(); len];
+    let mut parents = vec![usize::MAX; len];
+    // Shortest paths have fewer than `len` edges, so a node can still be relaxed
+    // at the `len`-th round only if there is a negative cycle.
+    let mut relaxed = None;
+    for _ in 0..len {
+        relaxed = None;
+        for (u, succs) in edges.iter().enumerate() {
+            for &(v, cost) in succs {
+                let new_cost = costs[u] + cost;
+                if new_cost < costs[v] {
+                    costs[v] = new_cost;
+                    parents[v] = u;
+                    relaxed = Some(v);
+                }
+            }
+        }
+        relaxed?;
+    }
+    let node = relaxed?;
+    Some(
+        cycle_from_parents(&parents, node)
+            .into_iter()
+            .map(|n| nodes[n].clone())
+            .collect(),
+    )
+}
+
+/// Find a cycle with the minimum mean weight in a weighted directed graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor, including possibly nodes that were not present in `nodes`.
+///
+/// The mean weight of a cycle is its total weight divided by its number of edges.
+/// [Karp's algorithm](https://doi.org/10.1016/0012-365X(78)90011-0) is used, which runs in
+/// `O(|V|·|E|)` time and `O(|V|²)` space, and requests the successors of a given node only once.
+/// Weights may be negative.
+///
+/// The function returns `None` if the graph has no cycle. Otherwise, it returns the nodes of a
+/// cycle with the minimum mean weight, in order and without repeating the first one, along with
+/// its mean weight. Cycles means are compared exactly, but the returned mean weight is computed
+/// using the division of `C`, which truncates the result if `C` is an integer type.
+///
+/// # Panics
+///
+/// This function panics if the number of nodes in the graph cannot be represented as a `C`.
+/// Means are compared exactly by multiplying the weight of a walk by the number of edges of
+/// another one. Such a product may overflow when `C` is an integer type and the magnitude of
+/// the weights is close to the largest value of `C` divided by `|V|²`, which panics in debug
+/// builds and gives an incorrect result otherwise. A wider type should be used for `C` in
+/// this case.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::minimum_mean_cycle;
+///
+/// // 1 -> 2 -> 3 -> 1 has weight 6 over 3 edges, 2 -> 4 -> 2 has weight 4 over 2 edges.
+/// let successors = |&n: &u32| match n {
+///     1 => vec![(2, 1)],
+///     2 => vec![(3, 2), (4, 1)],
+///     3 => vec![(1, 3)],
+///     _ => vec![(2, 3)],
+/// };
+/// let (cycle, mean) = minimum_mean_cycle(&[1], successors).unwrap();
+/// assert_eq!(mean, 2);
+/// assert_eq!(cycle.len(), 3);
+/// ```
+pub fn minimum_mean_cycle<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + FromPrimitive,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (nodes, edges) = index_graph(nodes, successors, |discovered, (s, c)| {
+        (discovered.insert_full(s).0, c)
+    });
+    let len = nodes.len();
+    let from_usize = |n: usize| C::from_usize(n).expect("number of nodes does not fit in C");
+    // `lt(a, b)` checks whether a.0/a.1 < b.0/b.1.
+    let lt = |a: (C, usize), b: (C, usize)| a.0 * from_usize(b.1) < b.0 * from_usize(a.1);
+    // costs[k][v] is the minimum cost of a walk with k edges ending at v.
+    let mut costs: Vec<Vec<Option<C>>> = vec![vec![Some(C::zero()); len]];
+    let mut parents = vec![vec![usize::MAX; len]];
+    for k in 1..=len {
+        let mut level = vec![None; len];
+        let mut level_parents = vec![usize::MAX; len];
+        for (u, succs) in edges.iter().enumerate() {
+            let Some(cost) = costs[k - 1][u] else {
+                continue;
+            };
+            for &(v, c) in succs {
+                let new_cost = cost + c;
+                if level[v].is_none_or(|old| new_cost < old) {
+                    level[v] = Some(new_cost);
+                    level_parents[v] = u;
+                }
+            }
+        }
+        costs.push(level);
+        parents.push(level_parents);
+    }
+    // Karp's formula: the minimum mean is the minimum over the nodes v of the
+    // maximum over k of (costs[len][v] - costs[k][v]) / (len - k).
+    let mut best: Option<((C, usize), usize)> = None;
+    for (v, &full) in costs[len].iter().enumerate() {
+        let Some(full) = full else {
+            continue;
+        };
+        let worst = (0..len)
+            .filter_map(|k| costs[k][v].map(|c| (full - c, len - k)))
+            .reduce(|a, b| if lt(a, b) { b } else { a })?;
+        if best.is_none_or(|(b, _)| lt(worst, b)) {
+            best = Some((worst, v));
+        }
+    }
+    let (_, mut v) = best?;
+    // Every cycle of the walk leading to v with `len` edges has the minimum mean, but pick the
+    // best one anyway to protect against ties in parents selection.
+    let mut walk = vec![v];
+    for k in (1..=len).rev() {
+        v = parents[k][v];
+        walk.push(v);
+    }
+    walk.reverse();
+    let weight = |a: usize, b: usize| {
+        edges[a]
+            .iter()
+            .filter(|&&(s, _)| s == b)
+            .map(|&(_, c)| c)
+            .min()
+            .unwrap() // Cannot fail
+    };
+    let mut stack: Vec<usize> = Vec::with_capacity(walk.len());
+    let mut positions = FxHashMap::default();
+    let mut best_cycle: Option<(Vec<usize>, (C, usize))> = None;
+    for node in walk {
+        if let Some(&p) = positions.get(&node) {
+            let cycle = stack.split_off(p);
+            for n in &cycle {
+                positions.remove(n);
+            }
+            let total = cycle
+                .iter()
+                .zip(cycle.iter().cycle().skip(1))
+                .fold(C::zero(), |acc, (&a, &b)| acc + weight(a, b));
+            let mean = (total, cycle.len());
+            if best_cycle.as_ref().is_none_or(|(_, m)| lt(mean, *m)) {
+                best_cycle = Some((cycle, mean));
+            }
+        }
+        positions.insert(node, stack.len());
+        stack.push(node);
+    }
+    let (cycle, (total, length)) = best_cycle?;
+    Some((
+        cycle.into_iter().map(|n| nodes[n].clone()).collect(),
+        total / from_usize(length),
+    ))
+}
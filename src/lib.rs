@@ -17,21 +17,37 @@
 //! - [BFS](directed/bfs/index.html): explore nearest successors first, then widen the search ([⇒ Wikipedia][BFS])
 //! - [Bidirectional search](directed/bfs/fn.bfs_bidirectional.html): simultaneously explore paths forwards from the start and backwards from the goal ([=> Wikipedia][Bidirectional search])
 //! - [Brent](directed/cycle_detection/index.html): find a cycle in an infinite sequence ([⇒ Wikipedia][Brent])
+//! - [Chinese postman](directed/chinese_postman/index.html): find the shortest closed walk going through every edge of a directed graph ([⇒ Wikipedia][Chinese postman])
+//! - [critical path](directed/dag/fn.critical_path.html): schedule dependent tasks and find the ones which cannot be delayed ([⇒ Wikipedia][Critical path])
+//! - [DAG paths](directed/dag/index.html): find the shortest or longest paths in a directed acyclic graph in linear time ([⇒ Wikipedia][DAG paths])
 //! - [DFS](directed/dfs/index.html): explore a graph by going as far as possible, then backtrack ([⇒ Wikipedia][DFS])
 //! - [Dijkstra](directed/dijkstra/index.html): find the shortest path in a weighted graph ([⇒ Wikipedia][Dijkstra])
+//! - [dominators](directed/dominators/index.html): find the nodes through which every path from a root goes, and build the dominator tree ([⇒ Wikipedia][Dominators])
 //! - [Edmonds Karp](directed/edmonds_karp/index.html): find the maximum flow in a weighted graph ([⇒ Wikipedia][Edmonds Karp])
+//! - [elementary cycles](directed/elementary_cycles/index.html): enumerate all the elementary cycles of a directed graph ([⇒ Wikipedia][Elementary cycles])
+//! - [Eulerian trail](directed/eulerian/index.html): find a walk using every edge of a directed graph exactly once ([⇒ Wikipedia][Eulerian trail])
 //! - [Floyd](directed/cycle_detection/index.html): find a cycle in an infinite sequence ([⇒ Wikipedia][Floyd])
 //! - [Fringe](directed/fringe/index.html): find the shortest path in a weighted graph using an heuristic to guide the process ([⇒ Wikipedia][Fringe])
+//! - [functional graph](directed/functional_graph/index.html): decompose a function over a finite domain into cycles and the trees feeding into them ([⇒ Wikipedia][Functional graph])
 //! - [IDA*](directed/idastar/index.html): explore longer and longer paths in a weighted graph at the cost of multiple similar examinations ([⇒ Wikipedia][IDA*])
 //! - [IDDFS](directed/iddfs/index.html): explore longer and longer paths in an unweighted graph at the cost of multiple similar examinations ([⇒ Wikipedia][IDDFS])
+//! - [incremental topological sorting](directed/incremental_topo/index.html): maintain a topological order while edges are added to a directed acyclic graph ([⇒ Wikipedia][Incremental topological sorting])
 //! - [paths counting](directed/count_paths/index.html): count the paths to the destination in an acyclic graph
 //! - [strongly connected components](directed/strongly_connected_components/index.html): find strongly connected components in a directed graph ([⇒ Wikipedia][Strongly connected components])
 //! - [topological sorting](directed/topological_sort/index.html): find an acceptable topological order in a directed graph ([⇒ Wikipedia][Topological sorting])
+//! - [transitive closure and reduction](directed/transitive/index.html): compute the reachability relation of a directed graph, or remove redundant edges from a directed acyclic graph ([⇒ Wikipedia][Transitive reduction])
+//! - [weakly connected components](directed/weakly_connected_components/index.html): find weakly connected components in a directed graph ([⇒ Wikipedia][Weakly connected components])
+//! - [weighted cycles](directed/weighted_cycles/index.html): find a negative cycle or a cycle with the minimum mean weight in a weighted graph ([⇒ Wikipedia][Minimum mean weight cycle])
 //! - [Yen](directed/yen/index.html): find k-shortest paths using Dijkstra ([⇒ Wikipedia][Yen])
 //!
 //! ### Undirected graphs
 //!
+//! - [biconnected components](undirected/biconnected/index.html): find articulation points, bridges, biconnected and 2-edge-connected components, and the block-cut tree ([⇒ Wikipedia][Biconnected component])
+//! - [bipartite graphs](undirected/bipartite/index.html): split vertices into two sides with no edge inside a side, or find an odd cycle ([⇒ Wikipedia][Bipartite graph])
+//! - [Chinese postman](undirected/chinese_postman/index.html): find the shortest closed walk going through every edge of an undirected graph ([⇒ Wikipedia][Chinese postman])
+//! - [coloring](undirected/coloring/index.html): color vertices so that connected ones get different colors, heuristically or exactly ([⇒ Wikipedia][Graph coloring])
 //! - [connected components](undirected/connected_components/index.html): find disjoint connected sets of vertices ([⇒ Wikipedia][Connected components])
+//! - [Eulerian trail](undirected/eulerian/index.html): find a walk using every edge of an undirected graph exactly once ([⇒ Wikipedia][Eulerian trail])
 //! - [Kruskal](undirected/kruskal/index.html): find a minimum-spanning-tree ([⇒ Wikipedia][Kruskal])
 //! - [Prim](undirected/prim/index.html): find a minimum-spanning-tree ([⇒ Wikipedia][Prim])
 //! - [cliques](undirected/cliques/index.html): find maximum cliques in a graph ([= Wikipedia][BronKerbosch])
@@ -40,6 +56,13 @@
 //!
 //! - [Kuhn-Munkres](kuhn_munkres/index.html) (Hungarian algorithm): find the maximum (or minimum) matching in a weighted bipartite graph ([⇒ Wikipedia][Kuhn-Munkres])
 //!
+//! ### Travelling salesman
+//!
+//! - [2-opt](tsp/fn.two_opt.html) and [Or-opt](tsp/fn.or_opt.html): improve a tour through local search ([⇒ Wikipedia][2-opt])
+//! - [Christofides](tsp/fn.christofides_tour.html): build a tour at most 1.5 times as costly as the cheapest one when costs form a metric ([⇒ Wikipedia][Christofides])
+//! - [Held-Karp](tsp/fn.held_karp_cycle.html): find the cheapest tour or path going through every city in exponential time ([⇒ Wikipedia][Held-Karp])
+//! - [nearest neighbour](tsp/fn.nearest_neighbour_tour.html): build a tour by always going to the closest city not visited yet ([⇒ Wikipedia][Nearest neighbour])
+//!
 //! ### Miscellaneous structures
 //!
 //! - A [`Grid`](grid/index.html) type representing a rectangular grid in which vertices can be added or removed, with automatic creation of edges between adjacent vertices.
@@ -88,25 +111,43 @@
 //!
 //! The minimum supported Rust version (MSRV) is Rust 1.87.0.
 //!
+//! [2-opt]: https://en.wikipedia.org/wiki/2-opt
 //! [A*]: https://en.wikipedia.org/wiki/A*_search_algorithm
 //! [BFS]: https://en.wikipedia.org/wiki/Breadth-first_search
+//! [Biconnected component]: https://en.wikipedia.org/wiki/Biconnected_component
 //! [Bidirectional search]: https://en.wikipedia.org/wiki/Bidirectional_search
+//! [Bipartite graph]: https://en.wikipedia.org/wiki/Bipartite_graph
 //! [Brent]: https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
 //! [BronKerbosch]: https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm
+//! [Chinese postman]: https://en.wikipedia.org/wiki/Chinese_postman_problem
+//! [Christofides]: https://en.wikipedia.org/wiki/Christofides_algorithm
 //! [Connected components]: https://en.wikipedia.org/wiki/Connected_component_(graph_theory)
+//! [Critical path]: https://en.wikipedia.org/wiki/Critical_path_method
+//! [DAG paths]: https://en.wikipedia.org/wiki/Longest_path_problem#Acyclic_graphs
 //! [DFS]: https://en.wikipedia.org/wiki/Depth-first_search
 //! [Dijkstra]: https://en.wikipedia.org/wiki/Dijkstra's_algorithm
+//! [Dominators]: https://en.wikipedia.org/wiki/Dominator_(graph_theory)
 //! [Edmonds Karp]: https://en.wikipedia.org/wiki/Edmonds–Karp_algorithm
+//! [Elementary cycles]: https://en.wikipedia.org/wiki/Cycle_(graph_theory)#Cycle_detection
+//! [Eulerian trail]: https://en.wikipedia.org/wiki/Eulerian_path
 //! [Floyd]: https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
 //! [Fringe]: https://en.wikipedia.org/wiki/Fringe_search
+//! [Functional graph]: https://en.wikipedia.org/wiki/Pseudoforest#Graphs_of_functions
+//! [Graph coloring]: https://en.wikipedia.org/wiki/Graph_coloring
+//! [Held-Karp]: https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm
 //! [IDA*]: https://en.wikipedia.org/wiki/Iterative_deepening_A*
 //! [IDDFS]: https://en.wikipedia.org/wiki/Iterative_deepening_depth-first_search
+//! [Incremental topological sorting]: https://en.wikipedia.org/wiki/Topological_sorting#Incremental_algorithms
 //! [Kruskal]: https://en.wikipedia.org/wiki/Kruskal's_algorithm
 //! [Kuhn-Munkres]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+//! [Minimum mean weight cycle]: https://en.wikipedia.org/wiki/Minimum_mean_weight_cycle
+//! [Nearest neighbour]: https://en.wikipedia.org/wiki/Nearest_neighbour_algorithm
 //! [Prim]: https://en.wikipedia.org/wiki/Prim's_algorithm
 //! [Rust]: https://rust-lang.org/
 //! [Strongly connected components]: https://en.wikipedia.org/wiki/Strongly_connected_component
 //! [Topological sorting]: https://en.wikipedia.org/wiki/Topological_sorting
+//! [Transitive reduction]: https://en.wikipedia.org/wiki/Transitive_reduction
+//! [Weakly connected components]: https://en.wikipedia.org/wiki/Connectivity_(graph_theory)#Connected_vertices_and_graphs
 //! [Yen]: https://en.wikipedia.org/wiki/Yen's_algorithm
 
 use deprecate_until::deprecate_until;
@@ -116,9 +157,11 @@ pub mod directed;
 pub mod grid;
 pub mod kuhn_munkres;
 pub mod matrix;
+pub mod tsp;
 pub mod undirected;
 pub mod utils;
 
+mod blossom;
 mod noderefs;
 pub use noderefs::NodeRefs;
 
@@ -133,22 +176,38 @@ type FxIndexSet<K> = IndexSet<K, BuildHasherDefault<FxHasher>>;
 pub mod prelude {
     pub use crate::directed::astar::*;
     pub use crate::directed::bfs::*;
+    pub use crate::directed::chinese_postman::*;
     pub use crate::directed::count_paths::*;
     pub use crate::directed::cycle_detection::*;
+    pub use crate::directed::dag::*;
     pub use crate::directed::dfs::*;
     pub use crate::directed::dijkstra::*;
+    pub use crate::directed::dominators::*;
     pub use crate::directed::edmonds_karp::*;
+    pub use crate::directed::elementary_cycles::*;
+    pub use crate::directed::eulerian::*;
     pub use crate::directed::fringe::*;
+    pub use crate::directed::functional_graph::*;
     pub use crate::directed::idastar::*;
     pub use crate::directed::iddfs::*;
+    pub use crate::directed::incremental_topo::*;
     pub use crate::directed::strongly_connected_components::*;
     pub use crate::directed::topological_sort::*;
+    pub use crate::directed::transitive::*;
+    pub use crate::directed::weakly_connected_components::*;
+    pub use crate::directed::weighted_cycles::*;
     pub use crate::directed::yen::*;
     pub use crate::grid::*;
     pub use crate::kuhn_munkres::*;
     pub use crate::matrix::*;
+    pub use crate::tsp::*;
+    pub use crate::undirected::biconnected::*;
+    pub use crate::undirected::bipartite::*;
+    pub use crate::undirected::chinese_postman::*;
     pub use crate::undirected::cliques::*;
+    pub use crate::undirected::coloring::*;
     pub use crate::undirected::connected_components::*;
+    pub use crate::undirected::eulerian::*;
     pub use crate::undirected::kruskal::*;
     pub use crate::utils::*;
 }
@@ -0,0 +1,489 @@
+//! Solve the [travelling salesman
+//! problem](https://en.wikipedia.org/wiki/Travelling_salesman_problem) on a matrix
+//! of pairwise costs.
+//!
+//! Cities are the row (and column) indices of a square [`Matrix`], whose element at
+//! `(i, j)` is the cost of going from city `i` to city `j`. Costs do not need to be
+//! symmetric unless stated otherwise.
+//!
+//! Exact solvers only handle a small number of cities. For larger instances, a tour can
+//! be built using a heuristic such as [`nearest_neighbour_tour`] or [`christofides_tour`],
+//! then improved using a local search such as [`two_opt`] or [`or_opt`].
+
+use crate::blossom::min_weight_perfect_matching;
+use crate::matrix::Matrix;
+use crate::undirected::eulerian::undirected_eulerian_circuit;
+use crate::undirected::kruskal::kruskal_indices;
+use num_traits::{Signed, Zero};
+
+// Held-Karp dynamic programming over the subsets of `cities`. The table element at
+// index `subset * cities.len() + end` is the cost of the cheapest path going through
+// exactly the cities of `subset` and ending at `cities[end]`, after starting from
+// `start` if it is given. Return the cheapest path going through all the cities,
+// coming back to `start` if `close` is set, along with its cost.
+fn held_karp<C>(
+    costs: &Matrix<C>,
+    cities: &[usize],
+    start: Option<usize>,
+    close: bool,
+) -> (Vec<usize>, C)
+where
+    C: Zero + Ord + Copy,
+{
+    let m = cities.len();
+    assert!(
+        m < usize::BITS as usize,
+        "too many cities for the Held-Karp algorithm"
+    );
+    let cost = |a: usize, b: usize| costs[(cities[a], cities[b])];
+    let first = |j: usize| start.map_or_else(C::zero, |s| costs[(s, cities[j])]);
+    let full = (1usize << m) - 1;
+    let mut table = vec![C::zero(); (full + 1) * m];
+    for subset in 1..=full {
+        for end in (0..m).filter(|&end| subset & (1 << end) != 0) {
+            let rest = subset & !(1 << end);
+            table[subset * m + end] = if rest == 0 {
+                first(end)
+            } else {
+                (0..m)
+                    .filter(|&k| rest & (1 << k) != 0)
+                    .map(|k| table[rest * m + k] + cost(k, end))
+                    .min()
+                    .unwrap() // Cannot fail
+            };
+        }
+    }
+    let last = |j: usize| {
+        let total = table[full * m + j];
+        match (start, close) {
+            (Some(s), true) => total + costs[(cities[j], s)],
+            _ => total,
+        }
+    };
+    let Some(mut end) = (0..m).min_by_key(|&j| last(j)) else {
+        return (start.into_iter().collect(), C::zero());
+    };
+    let total = last(end);
+    // Walk back through the table to rebuild the path.
+    let mut path = vec![cities[end]];
+    let mut subset = full;
+    loop {
+        let rest = subset & !(1 << end);
+        if rest == 0 {
+            break;
+        }
+        let target = table[subset * m + end];
+        end = (0..m)
+            .find(|&k| rest & (1 << k) != 0 && table[rest * m + k] + cost(k, end) == target)
+            .unwrap(); // Cannot fail
+        path.push(cities[end]);
+        subset = rest;
+    }
+    path.extend(start);
+    path.reverse();
+    (path, total)
+}
+
+/// Find the cheapest tour visiting every city exactly once and coming back to
+/// the starting city, using the [Held-Karp
+/// algorithm](https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm).
+///
+/// - `costs` is a square matrix whose element at `(i, j)` is the cost of going from
+///   city `i` to city `j`.
+/// - `start` is the city the tour starts from.
+///
+/// The tour is returned as the list of visited cities starting with `start`, without
+/// repeating it at the end, along with its total cost including the cost of coming back
+/// to `start`. A tour made of a single city has a null cost.
+///
+/// This function runs in `O(2ⁿ·n²)` time and uses `O(2ⁿ·n)` space, which makes it
+/// suitable for up to about 20 cities.
+///
+/// # Panics
+///
+/// This function panics if `costs` is not a square matrix, or if `start` is not a
+/// valid city.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{held_karp_cycle, Matrix};
+///
+/// let costs = Matrix::from_rows(vec![
+///     vec![0, 10, 15, 20],
+///     vec![10, 0, 35, 25],
+///     vec![15, 35, 0, 30],
+///     vec![20, 25, 30, 0],
+/// ])
+/// .unwrap();
+/// let (tour, cost) = held_karp_cycle(&costs, 0);
+/// assert_eq!(tour, vec![0, 2, 3, 1]);
+/// assert_eq!(cost, 80);
+/// ```
+#[must_use]
+pub fn held_karp_cycle<C>(costs: &Matrix<C>, start: usize) -> (Vec<usize>, C)
+where
+    C: Zero + Ord + Copy,
+{
+    assert!(costs.is_square(), "costs must be a square matrix");
+    assert!(start < costs.rows, "start must be a valid city");
+    let cities = (0..costs.rows).filter(|&c| c != start).collect::<Vec<_>>();
+    held_karp(costs, &cities, Some(start), true)
+}
+
+/// Find the cheapest path visiting every city exactly once, using the [Held-Karp
+/// algorithm](https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm).
+///
+/// - `costs` is a square matrix whose element at `(i, j)` is the cost of going from
+///   city `i` to city `j`.
+/// - `start` is the city the path starts from, or `None` if the path can start
+///   from any city.
+///
+/// The path is returned as the list of visited cities in order, along with its total
+/// cost. The path can end at any city.
+///
+/// This function runs in `O(2ⁿ·n²)` time and uses `O(2ⁿ·n)` space, which makes it
+/// suitable for up to about 20 cities.
+///
+/// # Panics
+///
+/// This function panics if `costs` is not a square matrix, or if `start` is not a
+/// valid city.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{held_karp_path, Matrix};
+///
+/// let costs = Matrix::from_rows(vec![
+///     vec![0, 10, 15, 20],
+///     vec![10, 0, 35, 25],
+///     vec![15, 35, 0, 30],
+///     vec![20, 25, 30, 0],
+/// ])
+/// .unwrap();
+/// assert_eq!(held_karp_path(&costs, None), (vec![3, 1, 0, 2], 50));
+/// assert_eq!(held_karp_path(&costs, Some(2)), (vec![2, 0, 1, 3], 50));
+/// ```
+#[must_use]
+pub fn held_karp_path<C>(costs: &Matrix<C>, start: Option<usize>) -> (Vec<usize>, C)
+where
+    C: Zero + Ord + Copy,
+{
+    assert!(costs.is_square(), "costs must be a square matrix");
+    assert!(
+        start.is_none_or(|s| s < costs.rows),
+        "start must be a valid city"
+    );
+    let cities = (0..costs.rows)
+        .filter(|&c| Some(c) != start)
+        .collect::<Vec<_>>();
+    held_karp(costs, &cities, start, false)
+}
+
+/// Compute the cost of a tour, including the cost of coming back from the last city
+/// to the first one. A tour made of a single city costs nothing.
+///
+/// # Panics
+///
+/// This function panics if a city of `tour` is not valid in `costs`.
+#[must_use]
+pub fn tour_cost<C>(costs: &Matrix<C>, tour: &[usize]) -> C
+where
+    C: Zero + Copy,
+{
+    if tour.len() < 2 {
+        return C::zero();
+    }
+    tour.iter()
+        .zip(tour.iter().cycle().skip(1))
+        .fold(C::zero(), |acc, (&a, &b)| acc + costs[(a, b)])
+}
+
+/// Build a tour visiting every city using the [nearest neighbour
+/// heuristic](https://en.wikipedia.org/wiki/Nearest_neighbour_algorithm): starting
+/// from `start`, the cheapest city not visited yet is always chosen next.
+///
+/// - `costs` is a square matrix whose element at `(i, j)` is the cost of going from
+///   city `i` to city `j`.
+/// - `start` is the city the tour starts from.
+///
+/// The tour is returned as the list of visited cities starting with `start`, without
+/// repeating it at the end, along with its total cost including the cost of coming back
+/// to `start`. This function runs in `O(n²)` time, and the tour can then be improved
+/// using [`two_opt`] or [`or_opt`].
+///
+/// # Panics
+///
+/// This function panics if `costs` is not a square matrix, or if `start` is not a
+/// valid city.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{nearest_neighbour_tour, Matrix};
+///
+/// let costs = Matrix::from_rows(vec![
+///     vec![0, 10, 15, 20],
+///     vec![10, 0, 35, 25],
+///     vec![15, 35, 0, 30],
+///     vec![20, 25, 30, 0],
+/// ])
+/// .unwrap();
+/// assert_eq!(nearest_neighbour_tour(&costs, 0), (vec![0, 1, 3, 2], 80));
+/// ```
+#[must_use]
+pub fn nearest_neighbour_tour<C>(costs: &Matrix<C>, start: usize) -> (Vec<usize>, C)
+where
+    C: Zero + Ord + Copy,
+{
+    assert!(costs.is_square(), "costs must be a square matrix");
+    assert!(start < costs.rows, "start must be a valid city");
+    let mut visited = vec![false; costs.rows];
+    visited[start] = true;
+    let mut tour = vec![start];
+    let mut current = start;
+    while let Some(next) = (0..costs.rows)
+        .filter(|&c| !visited[c])
+        .min_by_key(|&c| costs[(current, c)])
+    {
+        visited[next] = true;
+        tour.push(next);
+        current = next;
+    }
+    let cost = tour_cost(costs, &tour);
+    (tour, cost)
+}
+
+/// Improve a tour in place using the
+/// [2-opt](https://en.wikipedia.org/wiki/2-opt) local search.
+///
+/// - `costs` is a square matrix whose element at `(i, j)` is the cost of going from
+///   city `i` to city `j`. Costs are assumed to be symmetric.
+/// - `tour` is a tour visiting every city once, without repeating the first city at
+///   the end.
+///
+/// As long as replacing two edges of the tour by two other edges makes the tour
+/// cheaper, the portion of the tour between them is reversed. The first city of the tour
+/// is left in place. The cost of the improved tour is returned.
+///
+/// Every pass over the tour takes `O(n²)` time.
+///
+/// # Panics
+///
+/// This function panics if a city of `tour` is not valid in `costs`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{two_opt, Matrix};
+///
+/// // Four corners of a square, in a self-crossing order.
+/// let points = [(0i32, 0i32), (1, 1), (0, 1), (1, 0)];
+/// let costs = Matrix::from_fn(4, 4, |(i, j)| {
+///     (points[i].0 - points[j].0).abs() + (points[i].1 - points[j].1).abs()
+/// });
+/// let mut tour = vec![0, 1, 2, 3];
+/// assert_eq!(two_opt(&costs, &mut tour), 4);
+/// assert_eq!(tour, vec![0, 2, 1, 3]);
+/// ```
+#[expect(clippy::many_single_char_names)]
+pub fn two_opt<C>(costs: &Matrix<C>, tour: &mut [usize]) -> C
+where
+    C: Zero + Ord + Copy,
+{
+    let n = tour.len();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n.saturating_sub(2) {
+            for j in i + 2..n {
+                let (a, b) = (tour[i], tour[i + 1]);
+                let (c, d) = (tour[j], tour[(j + 1) % n]);
+                if d == a {
+                    continue;
+                }
+                if costs[(a, c)] + costs[(b, d)] < costs[(a, b)] + costs[(c, d)] {
+                    tour[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+    tour_cost(costs, tour)
+}
+
+/// Improve a tour in place using the Or-opt local search.
+///
+/// - `costs` is a square matrix whose element at `(i, j)` is the cost of going from
+///   city `i` to city `j`. Costs do not need to be symmetric.
+/// - `tour` is a tour visiting every city once, without repeating the first city at
+///   the end.
+///
+/// As long as moving a segment of one, two or three consecutive cities elsewhere in the
+/// tour makes it cheaper, the segment is moved without being reversed. The first city of
+/// the tour is left in place. The cost of the improved tour is returned.
+///
+/// Every pass over the tour takes `O(n²)` time.
+///
+/// # Panics
+///
+/// This function panics if a city of `tour` is not valid in `costs`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{or_opt, tour_cost, Matrix};
+///
+/// // Cities on a line, with city 4 visited at the wrong place.
+/// let costs = Matrix::from_fn(6, 6, |(i, j): (usize, usize)| i.abs_diff(j));
+/// let mut tour = vec![0, 4, 1, 2, 3, 5];
+/// assert_eq!(tour_cost(&costs, &tour), 16);
+/// // The line is now travelled once in each direction.
+/// assert_eq!(or_opt(&costs, &mut tour), 10);
+/// assert_eq!(tour[0], 0);
+/// ```
+pub fn or_opt<C>(costs: &Matrix<C>, tour: &mut [usize]) -> C
+where
+    C: Zero + Ord + Copy,
+{
+    let n = tour.len();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for len in 1..=3.min(n) {
+            // Moving a segment needs at least two other cities.
+            if n - len < 2 {
+                break;
+            }
+            for i in 0..n {
+                let (first, last) = (tour[i], tour[(i + len - 1) % n]);
+                // The other cities, in order, starting after the segment.
+                let rest = |k: usize| tour[(i + len + k) % n];
+                let (before, after) = (rest(n - len - 1), rest(0));
+                let removed = costs[(before, first)] + costs[(last, after)];
+                let Some(k) = (0..n - len - 1).find(|&k| {
+                    let (x, y) = (rest(k), rest(k + 1));
+                    costs[(before, after)] + costs[(x, first)] + costs[(last, y)]
+                        < removed + costs[(x, y)]
+                }) else {
+                    continue;
+                };
+                let start = tour[0];
+                let segment = (0..len).map(|s| tour[(i + s) % n]).collect::<Vec<_>>();
+                let others = (0..n - len).map(rest).collect::<Vec<_>>();
+                let mut moved = others[..=k]
+                    .iter()
+                    .chain(&segment)
+                    .chain(&others[k + 1..])
+                    .copied()
+                    .collect::<Vec<_>>();
+                let position = moved.iter().position(|&c| c == start).unwrap(); // Cannot fail
+                moved.rotate_left(position);
+                tour.copy_from_slice(&moved);
+                improved = true;
+            }
+        }
+    }
+    tour_cost(costs, tour)
+}
+
+/// Build a tour visiting every city using the [Christofides
+/// heuristic](https://en.wikipedia.org/wiki/Christofides_algorithm).
+///
+/// - `costs` is a square matrix whose element at `(i, j)` is the cost of going from
+///   city `i` to city `j`. Costs are assumed to be symmetric and to satisfy the triangle
+///   inequality, and only the upper triangle of the matrix is used.
+/// - `start` is the city the tour starts from.
+///
+/// A minimum spanning tree is built using [Kruskal's
+/// algorithm](crate::undirected::kruskal), and the cities with an odd degree in this tree
+/// are paired using a minimum weight perfect matching. An Eulerian circuit of the union of
+/// the tree and the matching is then built, and cities are visited in the order of their
+/// first appearance in the circuit. The tour cost is guaranteed to be at most 1.5 times the
+/// optimal one.
+///
+/// The tour is returned as the list of visited cities starting with `start`, without
+/// repeating it at the end, along with its total cost including the cost of coming back
+/// to `start`. This function runs in `O(n³)` time, and the tour can then be improved
+/// using [`two_opt`] or [`or_opt`].
+///
+/// # Panics
+///
+/// This function panics if `costs` is not a square matrix, or if `start` is not a
+/// valid city.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{christofides_tour, held_karp_cycle, Matrix};
+///
+/// let points = [(0i32, 0i32), (3, 0), (6, 0), (6, 4), (3, 4), (0, 4), (2, 2)];
+/// let costs = Matrix::from_fn(7, 7, |(i, j)| {
+///     (points[i].0 - points[j].0).abs() + (points[i].1 - points[j].1).abs()
+/// });
+/// let (tour, cost) = christofides_tour(&costs, 0);
+/// assert_eq!(tour[0], 0);
+/// let (_, optimal) = held_karp_cycle(&costs, 0);
+/// assert!(2 * cost <= 3 * optimal);
+/// ```
+#[must_use]
+pub fn christofides_tour<C>(costs: &Matrix<C>, start: usize) -> (Vec<usize>, C)
+where
+    C: Signed + Ord + Copy,
+{
+    assert!(costs.is_square(), "costs must be a square matrix");
+    assert!(start < costs.rows, "start must be a valid city");
+    let n = costs.rows;
+    let edges = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j, costs[(i, j)])))
+        .collect::<Vec<_>>();
+    let mut multigraph = kruskal_indices(n, edges)
+        .map(|(i, j, _)| (i, j, ()))
+        .collect::<Vec<_>>();
+    let mut degrees = vec![0; n];
+    for &(i, j, ()) in &multigraph {
+        degrees[i] += 1;
+        degrees[j] += 1;
+    }
+    let odd = (0..n).filter(|&c| degrees[c] % 2 == 1).collect::<Vec<_>>();
+    let pairs = (0..odd.len())
+        .flat_map(|a| (a + 1..odd.len()).map(move |b| (a, b)))
+        .map(|(a, b)| (a, b, costs[(odd[a].min(odd[b]), odd[a].max(odd[b]))]))
+        .collect::<Vec<_>>();
+    // A complete graph with an even number of vertices always has a perfect matching.
+    let mates = min_weight_perfect_matching(odd.len(), &pairs).unwrap(); // Cannot fail
+    multigraph.extend(
+        mates
+            .iter()
+            .enumerate()
+            .filter(|&(a, &b)| a < b)
+            .map(|(a, &b)| (odd[a], odd[b], ())),
+    );
+    // The multigraph is connected and all its vertices have an even degree.
+    let circuit = undirected_eulerian_circuit(&multigraph)
+        .unwrap() // Cannot fail
+        .into_iter()
+        .map(|(&c, _, ())| c)
+        .collect::<Vec<_>>();
+    // Shortcut the circuit, read from an occurrence of `start`.
+    let position = circuit.iter().position(|&c| c == start).unwrap_or(0);
+    let mut visited = vec![false; n];
+    let mut tour = Vec::with_capacity(n);
+    for c in std::iter::once(start)
+        .chain(circuit[position..].iter().copied())
+        .chain(circuit[..position].iter().copied())
+    {
+        if !visited[c] {
+            visited[c] = true;
+            tour.push(c);
+        }
+    }
+    // Only the upper triangle of the matrix is read, including for the way back.
+    let cost = tour
+        .iter()
+        .zip(tour.iter().cycle().skip(1))
+        .take(if n < 2 { 0 } else { n })
+        .fold(C::zero(), |acc, (&a, &b)| acc + costs[(a.min(b), a.max(b))]);
+    (tour, cost)
+}
@@ -0,0 +1,268 @@
+//! Find the [articulation points, bridges and biconnected
+//! components](https://en.wikipedia.org/wiki/Biconnected_component) of an undirected graph,
+//! as well as its 2-edge-connected components and its block-cut tree.
+
+use crate::directed::index_graph;
+use rustc_hash::FxHashSet;
+use std::hash::Hash;
+
+/// Articulation points, bridges and components of an undirected graph, as computed by
+/// [`biconnectivity`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Biconnectivity<N> {
+    /// Nodes whose removal increases the number of connected components, in
+    /// the order in which they are discovered.
+    pub articulation_points: Vec<N>,
+    /// Edges whose removal increases the number of connected components. Every
+    /// bridge is given as a pair of nodes, with the node closest to the start of the
+    /// depth-first search first.
+    pub bridges: Vec<(N, N)>,
+    /// Biconnected components, also called blocks, as a partition of the edges. Two edges
+    /// belong to the same block if they lie on a common simple cycle, and every bridge forms
+    /// a block by itself. Edges are oriented in the direction in which the depth-first
+    /// search first traverses them.
+    pub components: Vec<Vec<(N, N)>>,
+    /// 2-edge-connected components, as a partition of the nodes. Two nodes belong to the
+    /// same component if they stay connected whatever edge is removed, that is if they
+    /// are connected without using any bridge.
+    pub two_edge_connected_components: Vec<Vec<N>>,
+    /// Edges of the block-cut tree (or forest), linking the index of a block in
+    /// [`components`](Self::components) to every articulation point it contains.
+    pub block_cut_tree: Vec<(usize, N)>,
+}
+
+// Depth-first search state for a graph whose nodes are indices.
+struct Search<'a> {
+    edges: &'a [Vec<usize>],
+    // Discovery order of every node, or `usize::MAX` if it has not been reached yet.
+    order: Vec<usize>,
+    // Smallest discovery order reachable from the subtree of every node using
+    // at most one edge not belonging to the tree.
+    low: Vec<usize>,
+    articulation_points: Vec<bool>,
+    bridges: Vec<(usize, usize)>,
+    // Edges traversed and not assigned to a block yet.
+    edges_stack: Vec<(usize, usize)>,
+    components: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a> Search<'a> {
+    fn new(edges: &'a [Vec<usize>]) -> Self {
+        Self {
+            edges,
+            order: vec![usize::MAX; edges.len()],
+            low: vec![usize::MAX; edges.len()],
+            articulation_points: vec![false; edges.len()],
+            bridges: Vec::new(),
+            edges_stack: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    fn run(&mut self) {
+        let mut count = 0;
+        for root in 0..self.edges.len() {
+            if self.order[root] != usize::MAX {
+                continue;
+            }
+            self.order[root] = count;
+            self.low[root] = count;
+            count += 1;
+            let mut root_children = 0;
+            // Nodes on the current path, along with their parent, the index of the next
+            // neighbour to look at, whether the edge to the parent has been skipped, and
+            // the position of this edge in the edges stack.
+            let mut stack = vec![(root, usize::MAX, 0, false, 0)];
+            while let Some((node, parent, next, skipped, position)) = stack.last_mut() {
+                let (node, parent, position) = (*node, *parent, *position);
+                if let Some(&neighbour) = self.edges[node].get(*next) {
+                    *next += 1;
+                    // Only one of the parallel edges leading to the parent is the tree edge.
+                    if neighbour == parent && !*skipped {
+                        *skipped = true;
+                    } else if self.order[neighbour] == usize::MAX {
+                        self.order[neighbour] = count;
+                        self.low[neighbour] = count;
+                        count += 1;
+                        stack.push((neighbour, node, 0, false, self.edges_stack.len()));
+                        self.edges_stack.push((node, neighbour));
+                    } else if self.order[neighbour] < self.order[node] {
+                        // Back edges are seen from both ends, but recorded only once.
+                        self.low[node] = self.low[node].min(self.order[neighbour]);
+                        self.edges_stack.push((node, neighbour));
+                    }
+                    continue;
+                }
+                stack.pop();
+                if parent == usize::MAX {
+                    continue;
+                }
+                self.low[parent] = self.low[parent].min(self.low[node]);
+                if self.low[node] > self.order[parent] {
+                    self.bridges.push((parent, node));
+                }
+                if self.low[node] >= self.order[parent] {
+                    // The parent separates this subtree from the rest of the graph.
+                    self.components.push(self.edges_stack.split_off(position));
+                    if parent != root {
+                        self.articulation_points[parent] = true;
+                    }
+                }
+                if parent == root {
+                    root_children += 1;
+                }
+            }
+            if root_children > 1 {
+                self.articulation_points[root] = true;
+            }
+        }
+    }
+}
+
+/// Find the articulation points, bridges, biconnected components, 2-edge-connected
+/// components and block-cut tree of an undirected graph.
+///
+/// - `nodes` is a collection of nodes.
+/// - `neighbours` returns a list of neighbours for a given node, including possibly nodes
+///   that were not present in `nodes`. It must be symmetric: if `b` is a neighbour of `a`,
+///   `a` must be a neighbour of `b`. A neighbour appearing several times denotes parallel
+///   edges, which are never bridges, and a node which is its own neighbour is ignored.
+///
+/// The graph is explored using a depth-first search from every node not reached yet, in the
+/// order of `nodes` first, then in the order in which nodes are discovered through
+/// `neighbours`. The search is iterative, so that large graphs cannot exhaust the stack.
+///
+/// A node without neighbours forms a 2-edge-connected component by itself, but belongs to
+/// no block. This complements [`connected_components`](super::connected_components),
+/// which only looks at whether nodes are connected at all.
+///
+/// This function runs in `O(n + e)` time, where `n` is the number of nodes and `e` the
+/// number of edges.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::biconnectivity;
+///
+/// // Two triangles sharing node 3, and node 5 hanging from node 4.
+/// let neighbours = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![1, 3],
+///     3 => vec![1, 2, 4, 6],
+///     4 => vec![3, 5, 6],
+///     5 => vec![4],
+///     _ => vec![3, 4],
+/// };
+/// let result = biconnectivity(&[1], neighbours);
+/// assert_eq!(result.articulation_points, vec![3, 4]);
+/// assert_eq!(result.bridges, vec![(4, 5)]);
+/// assert_eq!(result.components.len(), 3);
+/// assert_eq!(result.two_edge_connected_components, vec![vec![1, 2, 3, 4, 6], vec![5]]);
+/// assert_eq!(result.block_cut_tree.len(), 4);
+/// ```
+#[must_use]
+pub fn biconnectivity<N, FN, IN>(nodes: &[N], neighbours: FN) -> Biconnectivity<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (discovered, edges) = index_graph(nodes, neighbours, |discovered, n| {
+        discovered.insert_full(n).0
+    });
+    let mut search = Search::new(&edges);
+    search.run();
+    let mut articulation_points = (0..discovered.len())
+        .filter(|&n| search.articulation_points[n])
+        .collect::<Vec<_>>();
+    articulation_points.sort_unstable_by_key(|&n| search.order[n]);
+    let bridges = search
+        .bridges
+        .iter()
+        .flat_map(|&(a, b)| [(a, b), (b, a)])
+        .collect::<FxHashSet<_>>();
+    let mut reached = vec![false; discovered.len()];
+    let mut two_edge_connected_components = Vec::new();
+    for start in 0..discovered.len() {
+        if reached[start] {
+            continue;
+        }
+        reached[start] = true;
+        let mut component = vec![start];
+        let mut explored = 0;
+        while let Some(&node) = component.get(explored) {
+            explored += 1;
+            for &n in &edges[node] {
+                if !reached[n] && !bridges.contains(&(node, n)) {
+                    reached[n] = true;
+                    component.push(n);
+                }
+            }
+        }
+        two_edge_connected_components.push(component);
+    }
+    // Last block every articulation point has been linked to.
+    let mut linked = vec![usize::MAX; discovered.len()];
+    let mut block_cut_tree = Vec::new();
+    for (block, component) in search.components.iter().enumerate() {
+        for n in component.iter().flat_map(|&(a, b)| [a, b]) {
+            if search.articulation_points[n] && linked[n] != block {
+                linked[n] = block;
+                block_cut_tree.push((block, discovered[n].clone()));
+            }
+        }
+    }
+    let node_pair = |(a, b): (usize, usize)| (discovered[a].clone(), discovered[b].clone());
+    Biconnectivity {
+        articulation_points: articulation_points
+            .into_iter()
+            .map(|n| discovered[n].clone())
+            .collect(),
+        bridges: search.bridges.into_iter().map(node_pair).collect(),
+        components: search
+            .components
+            .into_iter()
+            .map(|component| component.into_iter().map(node_pair).collect())
+            .collect(),
+        two_edge_connected_components: two_edge_connected_components
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|n| discovered[n].clone())
+                    .collect()
+            })
+            .collect(),
+        block_cut_tree,
+    }
+}
+
+/// Find the articulation points of an undirected graph, that is the nodes whose removal
+/// increases the number of connected components.
+///
+/// See [`biconnectivity`] for a description of the parameters and of the order in which
+/// nodes are returned.
+#[must_use]
+pub fn articulation_points<N, FN, IN>(nodes: &[N], neighbours: FN) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    biconnectivity(nodes, neighbours).articulation_points
+}
+
+/// Find the bridges of an undirected graph, that is the edges whose removal increases
+/// the number of connected components.
+///
+/// See [`biconnectivity`] for a description of the parameters and of the order in which
+/// bridges are returned.
+#[must_use]
+pub fn bridges<N, FN, IN>(nodes: &[N], neighbours: FN) -> Vec<(N, N)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    biconnectivity(nodes, neighbours).bridges
+}
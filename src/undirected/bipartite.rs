@@ -0,0 +1,114 @@
+//! Check whether an undirected graph is
+//! [bipartite](https://en.wikipedia.org/wiki/Bipartite_graph), that is whether its
+//! vertices can be colored using two colors so that no edge links two vertices of the
+//! same color.
+
+use crate::directed::index_graph;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Split the vertices of an undirected graph into two sides so that every edge links
+/// a vertex of one side to a vertex of the other side.
+///
+/// - `nodes` is a collection of nodes.
+/// - `neighbours` returns a list of neighbours for a given node, including possibly nodes
+///   that were not present in `nodes`. An edge reported in one direction only is
+///   considered as going both ways, but only nodes in `nodes` or reported as neighbours
+///   are explored.
+///
+/// The graph is explored using a breadth-first search from every node not reached yet, and
+/// the first node of every connected component is put on the first side. Nodes are listed
+/// on each side in the order in which they are reached.
+///
+/// # Errors
+///
+/// If the graph is not bipartite, an odd cycle is returned as an error. It is given as the
+/// list of its nodes, without repeating the first one at the end. A node which is its own
+/// neighbour forms an odd cycle of length one.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bipartition;
+///
+/// // A square is bipartite.
+/// let square = |&n: &u32| vec![(n + 1) % 4, (n + 3) % 4];
+/// assert_eq!(bipartition(&[0], square), Ok((vec![0, 2], vec![1, 3])));
+///
+/// // A triangle is not.
+/// let triangle = |&n: &u32| vec![(n + 1) % 3, (n + 2) % 3];
+/// let cycle = bipartition(&[0], triangle).unwrap_err();
+/// assert_eq!(cycle.len(), 3);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn bipartition<N, FN, IN>(nodes: &[N], neighbours: FN) -> Result<(Vec<N>, Vec<N>), Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let (discovered, edges) = index_graph(nodes, neighbours, |discovered, n| {
+        discovered.insert_full(n).0
+    });
+    let mut adjacency = edges.clone();
+    for (a, succs) in edges.iter().enumerate() {
+        for &b in succs {
+            adjacency[b].push(a);
+        }
+    }
+    // Side of every reached node, and node it has been reached from.
+    let mut sides = vec![None; discovered.len()];
+    let mut parents = vec![usize::MAX; discovered.len()];
+    let mut order = Vec::with_capacity(discovered.len());
+    let mut queue = VecDeque::new();
+    for root in 0..discovered.len() {
+        if sides[root].is_some() {
+            continue;
+        }
+        sides[root] = Some(false);
+        queue.push_back(root);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            let side = sides[node].unwrap(); // Cannot fail
+            for &neighbour in &adjacency[node] {
+                match sides[neighbour] {
+                    None => {
+                        sides[neighbour] = Some(!side);
+                        parents[neighbour] = node;
+                        queue.push_back(neighbour);
+                    }
+                    Some(s) if s == side => {
+                        return Err(odd_cycle(&parents, node, neighbour)
+                            .into_iter()
+                            .map(|n| discovered[n].clone())
+                            .collect());
+                    }
+                    Some(_) => (),
+                }
+            }
+        }
+    }
+    let (first, second): (Vec<_>, Vec<_>) =
+        order.into_iter().partition(|&n| sides[n] == Some(false));
+    Ok((
+        first.into_iter().map(|n| discovered[n].clone()).collect(),
+        second.into_iter().map(|n| discovered[n].clone()).collect(),
+    ))
+}
+
+// Two nodes of the same side linked by an edge have the same depth in the
+// breadth-first search tree. Build the cycle going up from the first one to
+// their common ancestor, then down to the second one.
+fn odd_cycle(parents: &[usize], mut a: usize, mut b: usize) -> Vec<usize> {
+    let mut up = Vec::new();
+    let mut down = Vec::new();
+    while a != b {
+        up.push(a);
+        down.push(b);
+        a = parents[a];
+        b = parents[b];
+    }
+    up.push(a);
+    up.extend(down.into_iter().rev());
+    up
+}
@@ -0,0 +1,109 @@
+//! Find the shortest closed walk going through every edge of an undirected graph
+//! at least once, also known as the [Chinese postman
+//! problem](https://en.wikipedia.org/wiki/Chinese_postman_problem) or route inspection.
+
+use super::eulerian::undirected_eulerian_circuit;
+use crate::FxIndexSet;
+use crate::blossom::min_weight_perfect_matching;
+use crate::directed::dijkstra::dijkstra_all;
+use num_traits::Signed;
+use std::hash::Hash;
+
+/// Find the shortest closed walk going through every edge of an undirected
+/// weighted graph at least once.
+///
+/// - `edges` is a collection of weighted edges, as in [`kruskal`](super::kruskal::kruskal).
+///   Parallel edges and loops are allowed, and costs must not be negative.
+///
+/// The nodes with an odd degree are paired so that the total length of the shortest
+/// paths between the nodes of every pair is minimal, using a minimum weight perfect
+/// matching in a general graph computed with Edmonds' blossom algorithm. The edges
+/// of those shortest paths are traversed twice, and an [Eulerian
+/// circuit](super::eulerian::undirected_eulerian_circuit) of the resulting graph
+/// is returned.
+///
+/// The walk is returned as the list of traversed edges, oriented in the direction of
+/// travel and starting with the first node of the first edge, along with its total cost.
+/// The second node of every edge is the first node of the next one, and the second node of
+/// the last edge is the first node of the first one. An empty walk is returned if there are
+/// no edges.
+///
+/// The function returns `None` if the edges are not all connected together.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::undirected_chinese_postman;
+///
+/// // A square with one diagonal: the diagonal ends have an odd degree, and the diagonal
+/// // is the shortest path between them.
+/// let streets = [('a', 'b', 3), ('b', 'c', 3), ('c', 'd', 3), ('d', 'a', 3), ('a', 'c', 4)];
+/// let (walk, cost) = undirected_chinese_postman(&streets).unwrap();
+/// assert_eq!(cost, 20);
+/// assert_eq!(walk.len(), 6);
+/// assert_eq!(walk.iter().filter(|&&(a, b, _)| a.min(b) == &'a' && a.max(b) == &'c').count(), 2);
+/// ```
+#[expect(clippy::missing_panics_doc, clippy::type_complexity)]
+pub fn undirected_chinese_postman<N, C>(edges: &[(N, N, C)]) -> Option<(Vec<(&N, &N, C)>, C)>
+where
+    N: Hash + Eq,
+    C: Signed + Ord + Copy,
+{
+    let mut nodes = FxIndexSet::default();
+    let mut walk_edges = edges
+        .iter()
+        .enumerate()
+        .map(|(e, (a, b, _))| (nodes.insert_full(a).0, nodes.insert_full(b).0, e))
+        .collect::<Vec<_>>();
+    let mut neighbours = vec![Vec::new(); nodes.len()];
+    let mut degrees = vec![0usize; nodes.len()];
+    for &(a, b, e) in &walk_edges {
+        neighbours[a].push((b, e));
+        neighbours[b].push((a, e));
+        degrees[a] += 1;
+        degrees[b] += 1;
+    }
+    let odd = (0..nodes.len())
+        .filter(|&n| degrees[n] % 2 == 1)
+        .collect::<Vec<_>>();
+    let parents = odd
+        .iter()
+        .map(|start| {
+            dijkstra_all(start, |&n| {
+                neighbours[n]
+                    .iter()
+                    .map(|&(m, e)| (m, edges[e].2))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<Vec<_>>();
+    let pairs = (0..odd.len())
+        .flat_map(|i| (i + 1..odd.len()).map(move |j| (i, j)))
+        .filter_map(|(i, j)| parents[i].get(&odd[j]).map(|&(_, c)| (i, j, c)))
+        .collect::<Vec<_>>();
+    let mates = min_weight_perfect_matching(odd.len(), &pairs)?;
+    let mut cost = edges.iter().fold(C::zero(), |acc, &(_, _, c)| acc + c);
+    for (i, &j) in mates.iter().enumerate().filter(|&(i, &j)| i < j) {
+        // Duplicate the cheapest edges along the shortest path between the two nodes.
+        let mut node = odd[j];
+        cost = cost + parents[i][&node].1;
+        while node != odd[i] {
+            let parent = parents[i][&node].0;
+            let e = neighbours[node]
+                .iter()
+                .filter(|&&(m, _)| m == parent)
+                .map(|&(_, e)| e)
+                .min_by_key(|&e| edges[e].2)
+                .unwrap(); // Cannot fail
+            walk_edges.push((parent, node, e));
+            node = parent;
+        }
+    }
+    let walk = undirected_eulerian_circuit(&walk_edges).ok()?;
+    Some((
+        walk.into_iter()
+            .map(|(&a, &b, e)| (nodes[a], nodes[b], edges[e].2))
+            .collect(),
+        cost,
+    ))
+}
@@ -0,0 +1,326 @@
+//! Color the vertices of an undirected graph so that two connected vertices
+//! never get the same color, also known as [graph
+//! coloring](https://en.wikipedia.org/wiki/Graph_coloring).
+//!
+//! Colors are numbered from `0`, and every function of this module returns a mapping
+//! from every vertex to its color.
+
+use rustc_hash::FxHashSet;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Outcome of an exact [`k_coloring`] search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KColoring<N: Eq + Hash> {
+    /// The graph can be colored with the requested number of colors, using
+    /// this coloring.
+    Colorable(HashMap<N, usize>),
+    /// The graph cannot be colored with the requested number of colors.
+    NotColorable,
+    /// The search has been stopped before reaching a conclusion.
+    BudgetExceeded,
+}
+
+fn adjacency<N, FN>(vertices: &[N], connected: &mut FN) -> Vec<Vec<usize>>
+where
+    FN: FnMut(&N, &N) -> bool,
+{
+    let mut adjacency = vec![Vec::new(); vertices.len()];
+    for i in 0..vertices.len() {
+        for j in i + 1..vertices.len() {
+            if connected(&vertices[i], &vertices[j]) {
+                adjacency[i].push(j);
+                adjacency[j].push(i);
+            }
+        }
+    }
+    adjacency
+}
+
+// Smallest color not used by any of the neighbours. Uncolored vertices
+// have color `usize::MAX`.
+fn smallest_free_color(neighbours: &[usize], colors: &[usize]) -> usize {
+    let mut used = vec![false; neighbours.len() + 1];
+    for &n in neighbours {
+        if let Some(u) = used.get_mut(colors[n]) {
+            *u = true;
+        }
+    }
+    used.iter().position(|&u| !u).unwrap() // Cannot fail
+}
+
+fn greedy(adjacency: &[Vec<usize>], order: impl IntoIterator<Item = usize>) -> Vec<usize> {
+    let mut colors = vec![usize::MAX; adjacency.len()];
+    for v in order {
+        colors[v] = smallest_free_color(&adjacency[v], &colors);
+    }
+    colors
+}
+
+fn mapping<N>(vertices: Vec<N>, colors: Vec<usize>) -> HashMap<N, usize>
+where
+    N: Eq + Hash,
+{
+    vertices.into_iter().zip(colors).collect()
+}
+
+/// Color the vertices of an undirected graph greedily: every vertex, in the order of
+/// `vertices`, receives the smallest color not used by its already colored neighbours.
+///
+/// - `vertices` is the list of all nodes.
+/// - `connected` returns true if the two given nodes are connected.
+///
+/// The number of colors used is at most one more than the largest degree in the graph.
+/// This function runs in `O(n²)` time and calls `connected` once for every pair of
+/// vertices.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::greedy_coloring;
+///
+/// // Exams sharing a student cannot take place at the same time.
+/// let students = [vec!["alice", "bob"], vec!["bob", "carol"], vec!["dave"]];
+/// let slots = greedy_coloring(0..3, &mut |&a: &usize, &b: &usize| {
+///     students[a].iter().any(|s| students[b].contains(s))
+/// });
+/// assert_eq!(slots[&0], 0);
+/// assert_eq!(slots[&1], 1);
+/// assert_eq!(slots[&2], 0);
+/// ```
+#[must_use]
+pub fn greedy_coloring<N, FN, IN>(vertices: IN, connected: &mut FN) -> HashMap<N, usize>
+where
+    N: Eq + Hash,
+    FN: FnMut(&N, &N) -> bool,
+    IN: IntoIterator<Item = N>,
+{
+    let vertices = vertices.into_iter().collect::<Vec<_>>();
+    let adjacency = adjacency(&vertices, connected);
+    let colors = greedy(&adjacency, 0..vertices.len());
+    mapping(vertices, colors)
+}
+
+/// Color the vertices of an undirected graph using the Welsh-Powell heuristic:
+/// vertices are colored greedily by decreasing degree, every one of them receiving
+/// the smallest color not used by its already colored neighbours.
+///
+/// - `vertices` is the list of all nodes.
+/// - `connected` returns true if the two given nodes are connected.
+///
+/// Vertices with the same degree are colored in the order of `vertices`. This
+/// function runs in `O(n²)` time and calls `connected` once for every pair of
+/// vertices.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::welsh_powell_coloring;
+///
+/// // A star: the center is colored first.
+/// let colors = welsh_powell_coloring(1..=4, &mut |&a: &u32, &b: &u32| a.min(b) == 1 || a.max(b) == 1);
+/// assert_eq!(colors[&1], 0);
+/// assert!((2..=4).all(|n| colors[&n] == 1));
+/// ```
+#[must_use]
+pub fn welsh_powell_coloring<N, FN, IN>(vertices: IN, connected: &mut FN) -> HashMap<N, usize>
+where
+    N: Eq + Hash,
+    FN: FnMut(&N, &N) -> bool,
+    IN: IntoIterator<Item = N>,
+{
+    let vertices = vertices.into_iter().collect::<Vec<_>>();
+    let adjacency = adjacency(&vertices, connected);
+    let mut order = (0..vertices.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&v| Reverse(adjacency[v].len()));
+    let colors = greedy(&adjacency, order);
+    mapping(vertices, colors)
+}
+
+/// Color the vertices of an undirected graph using the
+/// [DSatur](https://en.wikipedia.org/wiki/DSatur) heuristic: the next vertex to color
+/// is always the one whose neighbours already use the largest number of distinct colors,
+/// and it receives the smallest color not used by them.
+///
+/// - `vertices` is the list of all nodes.
+/// - `connected` returns true if the two given nodes are connected.
+///
+/// Ties are broken by choosing the vertex with the largest degree, then the first one in
+/// the order of `vertices`. This heuristic colors bipartite graphs, cycles and wheels optimally.
+/// This function runs in `O(n²)` time and calls `connected` once for every pair of
+/// vertices.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dsatur_coloring;
+///
+/// // A cycle of length 6 is colored with two colors.
+/// let colors = dsatur_coloring(0..6, &mut |&a: &u32, &b: &u32| a.abs_diff(b) % 4 == 1);
+/// assert!((0..6).all(|n| colors[&n] < 2));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+#[must_use]
+pub fn dsatur_coloring<N, FN, IN>(vertices: IN, connected: &mut FN) -> HashMap<N, usize>
+where
+    N: Eq + Hash,
+    FN: FnMut(&N, &N) -> bool,
+    IN: IntoIterator<Item = N>,
+{
+    let vertices = vertices.into_iter().collect::<Vec<_>>();
+    let adjacency = adjacency(&vertices, connected);
+    let mut colors = vec![usize::MAX; vertices.len()];
+    let mut saturation = vec![FxHashSet::default(); vertices.len()];
+    for _ in 0..vertices.len() {
+        let v = (0..vertices.len())
+            .filter(|&v| colors[v] == usize::MAX)
+            .max_by_key(|&v| (saturation[v].len(), adjacency[v].len(), Reverse(v)))
+            .unwrap(); // Cannot fail
+        let color = smallest_free_color(&adjacency[v], &colors);
+        colors[v] = color;
+        for &n in &adjacency[v] {
+            saturation[n].insert(color);
+        }
+    }
+    mapping(vertices, colors)
+}
+
+// Backtracking search for a coloring using at most `k` colors.
+struct Search<'a> {
+    adjacency: &'a [Vec<usize>],
+    k: usize,
+    budget: usize,
+    colors: Vec<usize>,
+    // Number of neighbours of every vertex using every color.
+    counts: Vec<Vec<usize>>,
+    // Number of distinct colors used by the neighbours of every vertex.
+    saturation: Vec<usize>,
+}
+
+impl Search<'_> {
+    fn assign(&mut self, v: usize, color: usize) {
+        self.colors[v] = color;
+        for &n in &self.adjacency[v] {
+            if self.counts[n][color] == 0 {
+                self.saturation[n] += 1;
+            }
+            self.counts[n][color] += 1;
+        }
+    }
+
+    fn unassign(&mut self, v: usize, color: usize) {
+        self.colors[v] = usize::MAX;
+        for &n in &self.adjacency[v] {
+            self.counts[n][color] -= 1;
+            if self.counts[n][color] == 0 {
+                self.saturation[n] -= 1;
+            }
+        }
+    }
+
+    // Return `Some(true)` if the vertices could be colored, `Some(false)` if they
+    // could not, and `None` if the budget is exhausted. The search uses an explicit
+    // stack so that large graphs cannot exhaust the call stack.
+    fn search(&mut self) -> Option<bool> {
+        // Vertices being colored, along with the next color to try for them and the
+        // number of colors used before coloring them.
+        let mut stack: Vec<(usize, usize, usize)> = Vec::new();
+        let mut used = 0;
+        loop {
+            let Some(v) = (0..self.colors.len())
+                .filter(|&v| self.colors[v] == usize::MAX)
+                .max_by_key(|&v| (self.saturation[v], self.adjacency[v].len(), Reverse(v)))
+            else {
+                return Some(true);
+            };
+            stack.push((v, 0, used));
+            loop {
+                let (v, next, used_before) = stack.last_mut().unwrap(); // Cannot fail
+                let (v, used_before) = (*v, *used_before);
+                if self.colors[v] != usize::MAX {
+                    self.unassign(v, self.colors[v]);
+                }
+                // Colors are interchangeable, so only one unused color needs to be tried.
+                let Some(color) =
+                    (*next..self.k.min(used_before + 1)).find(|&c| self.counts[v][c] == 0)
+                else {
+                    stack.pop();
+                    if stack.is_empty() {
+                        return Some(false);
+                    }
+                    continue;
+                };
+                if self.budget == 0 {
+                    return None;
+                }
+                self.budget -= 1;
+                *next = color + 1;
+                self.assign(v, color);
+                used = used_before.max(color + 1);
+                break;
+            }
+        }
+    }
+}
+
+/// Check whether the vertices of an undirected graph can be colored using at most `k`
+/// colors, using an exact backtracking search.
+///
+/// - `vertices` is the list of all nodes.
+/// - `connected` returns true if the two given nodes are connected.
+/// - `k` is the maximum number of colors to use.
+/// - `budget` is the maximum number of color assignments the search can try before
+///   giving up.
+///
+/// The vertex colored next is chosen as in [`dsatur_coloring`], and colors which are not
+/// used yet are considered only once since they are interchangeable. As the problem is
+/// NP-complete, the search can take an exponential time, which `budget` allows bounding.
+/// The budget is expressed as a number of assignments rather than as a duration so that
+/// the outcome does not depend on the speed of the machine; a time limit can be emulated
+/// by retrying with increasing budgets until a deadline is reached. The search
+/// is iterative, so that large graphs cannot exhaust the stack. `connected` is called
+/// once for every pair of vertices.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{k_coloring, KColoring};
+///
+/// // A cycle of length 5 needs three colors.
+/// let mut connected = |&a: &u32, &b: &u32| a.abs_diff(b) % 3 == 1;
+/// assert_eq!(k_coloring(0..5, &mut connected, 2, 1000), KColoring::NotColorable);
+/// let KColoring::Colorable(colors) = k_coloring(0..5, &mut connected, 3, 1000) else {
+///     panic!("cycle must be 3-colorable");
+/// };
+/// assert!((0..5).all(|n| colors[&n] < 3));
+/// assert_ne!(colors[&0], colors[&4]);
+/// ```
+#[must_use]
+pub fn k_coloring<N, FN, IN>(
+    vertices: IN,
+    connected: &mut FN,
+    k: usize,
+    budget: usize,
+) -> KColoring<N>
+where
+    N: Eq + Hash,
+    FN: FnMut(&N, &N) -> bool,
+    IN: IntoIterator<Item = N>,
+{
+    let vertices = vertices.into_iter().collect::<Vec<_>>();
+    let adjacency = adjacency(&vertices, connected);
+    let mut search = Search {
+        adjacency: &adjacency,
+        k,
+        budget,
+        colors: vec![usize::MAX; vertices.len()],
+        counts: vec![vec![0; k]; vertices.len()],
+        saturation: vec![0; vertices.len()],
+    };
+    match search.search() {
+        Some(true) => KColoring::Colorable(mapping(vertices, search.colors)),
+        Some(false) => KColoring::NotColorable,
+        None => KColoring::BudgetExceeded,
+    }
+}
@@ -0,0 +1,174 @@
+//! Find an [Eulerian trail or circuit](https://en.wikipedia.org/wiki/Eulerian_path)
+//! in an undirected graph using Hierholzer's algorithm.
+
+use crate::FxIndexSet;
+use crate::directed::eulerian::EulerianError;
+use std::hash::Hash;
+
+fn eulerian<N, C>(edges: &[(N, N, C)], circuit: bool) -> Result<Vec<(&N, &N, C)>, EulerianError<&N>>
+where
+    N: Hash + Eq,
+    C: Clone,
+{
+    let mut nodes = FxIndexSet::default();
+    // Neighbours of every node, along with the index of the edge leading to them.
+    let mut neighbours: Vec<Vec<(usize, usize)>> = Vec::new();
+    for (e, (a, b, _)) in edges.iter().enumerate() {
+        let ia = nodes.insert_full(a).0;
+        let ib = nodes.insert_full(b).0;
+        neighbours.resize_with(nodes.len(), Vec::new);
+        neighbours[ia].push((ib, e));
+        neighbours[ib].push((ia, e));
+    }
+    let mut odd = neighbours
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| n.len() % 2 == 1)
+        .map(|(n, _)| n);
+    let start = match odd.next() {
+        None => 0,
+        Some(n) if circuit => return Err(EulerianError::Unbalanced(nodes[n])),
+        Some(n) => {
+            // The number of odd-degree nodes is always even, so the third
+            // one is the first one which cannot be a trail end.
+            if let Some(extra) = odd.nth(1) {
+                return Err(EulerianError::Unbalanced(nodes[extra]));
+            }
+            n
+        }
+    };
+    if edges.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut used = vec![false; edges.len()];
+    let mut next = vec![0; nodes.len()];
+    // Visited nodes, along with the index of the edge used to reach them.
+    let mut stack = vec![(start, usize::MAX)];
+    let mut trail = Vec::with_capacity(edges.len());
+    while let Some(&(node, edge)) = stack.last() {
+        while neighbours[node]
+            .get(next[node])
+            .is_some_and(|&(_, e)| used[e])
+        {
+            next[node] += 1;
+        }
+        if let Some(&(neighbour, e)) = neighbours[node].get(next[node]) {
+            used[e] = true;
+            stack.push((neighbour, e));
+            continue;
+        }
+        stack.pop();
+        if let Some(&(previous, _)) = stack.last() {
+            trail.push((previous, node, edge));
+        }
+    }
+    if let Some(e) = used.iter().position(|&u| !u) {
+        return Err(EulerianError::Disconnected(&edges[e].0));
+    }
+    Ok(trail
+        .into_iter()
+        .rev()
+        .map(|(a, b, e)| (nodes[a], nodes[b], edges[e].2.clone()))
+        .collect())
+}
+
+/// Find an Eulerian trail in an undirected graph, that is a walk which uses every edge
+/// exactly once.
+///
+/// - `edges` is a collection of weighted edges, as in [`kruskal`](super::kruskal::kruskal).
+///   Parallel edges and loops are allowed.
+///
+/// An Eulerian trail exists if the edges are all connected together, and if the degree of
+/// every node is even, except possibly for two nodes which are the ends of the trail. The
+/// trail starts with the first odd-degree node if there is one, or with the first node of
+/// the first edge otherwise.
+///
+/// The trail is returned as the list of its edges, oriented in the direction of travel:
+/// the second node of every edge is the first node of the next one. An empty list is
+/// returned if there are no edges. The trail is computed in linear time.
+///
+/// # Errors
+///
+/// - [`EulerianError::Unbalanced`] is returned with an odd-degree node in excess when there
+///   are more than two of them.
+/// - [`EulerianError::Disconnected`] is returned with a node whose edges cannot be reached
+///   from the start of the trail.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::undirected_eulerian_path;
+///
+/// // Draw a house with an X inside its walls without lifting the pen.
+/// let edges = [
+///     ('a', 'b', 1), ('b', 'c', 1), ('c', 'd', 1), ('d', 'a', 1),
+///     ('a', 'c', 1), ('b', 'd', 1), ('c', 'e', 1), ('e', 'd', 1),
+/// ];
+/// let trail = undirected_eulerian_path(&edges).unwrap();
+/// assert_eq!(trail.len(), edges.len());
+/// assert_eq!(*trail[0].0, 'a');
+/// assert_eq!(*trail[trail.len() - 1].1, 'b');
+/// assert!(trail.windows(2).all(|w| w[0].1 == w[1].0));
+/// ```
+pub fn undirected_eulerian_path<N, C>(
+    edges: &[(N, N, C)],
+) -> Result<Vec<(&N, &N, C)>, EulerianError<&N>>
+where
+    N: Hash + Eq,
+    C: Clone,
+{
+    eulerian(edges, false)
+}
+
+/// Find an Eulerian circuit in an undirected graph, that is a closed walk which uses every
+/// edge exactly once.
+///
+/// - `edges` is a collection of weighted edges, as in [`kruskal`](super::kruskal::kruskal).
+///   Parallel edges and loops are allowed.
+///
+/// An Eulerian circuit exists if the edges are all connected together, and if the degree
+/// of every node is even. The circuit starts with the first node of the first edge.
+///
+/// The circuit is returned as the list of its edges, oriented in the direction of travel:
+/// the second node of every edge is the first node of the next one, and the second node of
+/// the last edge is the first node of the first one. An empty list is returned if there are
+/// no edges. The circuit is computed in linear time.
+///
+/// # Errors
+///
+/// - [`EulerianError::Unbalanced`] is returned with a node whose degree is odd.
+/// - [`EulerianError::Disconnected`] is returned with a node whose edges cannot be reached
+///   from the start of the circuit.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{undirected_eulerian_circuit, EulerianError};
+///
+/// // The seven bridges of Königsberg.
+/// let bridges = [
+///     ("north", "island", 1), ("north", "island", 2), ("north", "east", 3),
+///     ("south", "island", 4), ("south", "island", 5), ("south", "east", 6),
+///     ("island", "east", 7),
+/// ];
+/// assert_eq!(
+///     undirected_eulerian_circuit(&bridges),
+///     Err(EulerianError::Unbalanced(&"north")),
+/// );
+///
+/// // Adding two bridges makes a closed walk possible.
+/// let mut bridges = bridges.to_vec();
+/// bridges.extend([("north", "south", 8), ("island", "east", 9)]);
+/// let circuit = undirected_eulerian_circuit(&bridges).unwrap();
+/// assert_eq!(circuit.len(), 9);
+/// assert_eq!(circuit[0].0, circuit[8].1);
+/// ```
+pub fn undirected_eulerian_circuit<N, C>(
+    edges: &[(N, N, C)],
+) -> Result<Vec<(&N, &N, C)>, EulerianError<&N>>
+where
+    N: Hash + Eq,
+    C: Clone,
+{
+    eulerian(edges, true)
+}
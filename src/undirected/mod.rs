@@ -1,6 +1,11 @@
 //! Algorithms for undirected graphs.
 
+pub mod biconnected;
+pub mod bipartite;
+pub mod chinese_postman;
 pub mod cliques;
+pub mod coloring;
 pub mod connected_components;
+pub mod eulerian;
 pub mod kruskal;
 pub mod prim;
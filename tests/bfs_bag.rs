@@ -0,0 +1,149 @@
+use itertools::Itertools;
+use pathfinding::NodeRefs;
+use pathfinding::prelude::*;
+
+#[test]
+fn multiple_sinks() {
+    // 1 --> 2 --> 4
+    //   --> 3 --> 4
+    //
+    // 2 --> 5 --> 6 --> 7
+    // 3 --> 5 --> 6 --> 7
+    let successors = |&n: &u32| match n {
+        1 => vec![2, 3],
+        2 | 3 => vec![4, 5],
+        5 => vec![6],
+        6 => vec![7],
+        _ => vec![],
+    };
+    let solutions = bfs_bag(&1, successors, |&n| n == 4 || n == 7).unwrap();
+    assert_eq!(
+        solutions.sorted().collect_vec(),
+        vec![vec![1, 2, 4], vec![1, 3, 4]]
+    );
+    assert_eq!(
+        count_shortest_paths(&1, successors, |&n| n == 4 || n == 7, 1usize),
+        2
+    );
+    let solutions = bfs_bag(&1, successors, |&n| n == 7).unwrap();
+    assert_eq!(
+        solutions.sorted().collect_vec(),
+        vec![vec![1, 2, 5, 6, 7], vec![1, 3, 5, 6, 7]]
+    );
+    assert_eq!(count_shortest_paths(&1, successors, |&n| n == 7, 1usize), 2);
+}
+
+#[test]
+fn start_is_goal() {
+    let solutions = bfs_bag(&1, |&n: &u32| vec![n + 1], |&n| n == 1).unwrap();
+    assert_eq!(solutions.collect_vec(), vec![vec![1]]);
+    assert_eq!(
+        count_shortest_paths(&1, |&n: &u32| vec![n + 1], |&n| n == 1, 1usize),
+        1
+    );
+}
+
+#[test]
+fn no_path() {
+    let successors = |&n: &u32| if n < 10 { vec![n + 1] } else { vec![] };
+    assert!(bfs_bag(&1, successors, |&n| n == 0).is_none());
+    assert_eq!(count_shortest_paths(&1, successors, |&n| n == 0, 1usize), 0);
+}
+
+#[test]
+fn multiple_starts() {
+    let successors = |&n: &u32| vec![n + 1, n + 2];
+    let starts = NodeRefs::from_iter(&[1, 2, 3]);
+    let solutions = bfs_bag(starts.clone(), successors, |&n| n == 5).unwrap();
+    assert_eq!(solutions.collect_vec(), vec![vec![3, 5]]);
+    assert_eq!(
+        count_shortest_paths(starts, successors, |&n| n == 5, 1usize),
+        1
+    );
+    let starts = NodeRefs::from_iter(&[1, 2]);
+    let solutions = bfs_bag(starts.clone(), successors, |&n| n == 5).unwrap();
+    assert_eq!(
+        solutions.sorted().collect_vec(),
+        vec![vec![1, 3, 5], vec![2, 3, 5], vec![2, 4, 5]]
+    );
+    assert_eq!(
+        count_shortest_paths(starts, successors, |&n| n == 5, 1usize),
+        3
+    );
+}
+
+#[test]
+fn numerous_solutions() {
+    const N: usize = 10;
+    const GOAL: usize = 3 * N;
+    //     ---> 1 --
+    //    /     |   \
+    // 0--      |    --> 3 … --> 3*N with 2^N paths of length 2*N
+    //    \     v   /            (path from 1 to 2 is unused)
+    //     ---> 2 --
+    let successors = |&n: &usize| match n {
+        x if x % 3 == 2 => vec![x + 1],
+        x => vec![x + 1, x + 2],
+    };
+    assert_eq!(
+        count_shortest_paths(&0, successors, |&n| n == GOAL, 1usize),
+        1 << N
+    );
+    let solutions = bfs_bag(&0, successors, |&n| n == GOAL).unwrap();
+    assert_eq!(
+        solutions.map(|p| p.len()).collect_vec(),
+        vec![2 * N + 1; 1 << N]
+    );
+}
+
+#[test]
+fn knight_moves() {
+    #[expect(clippy::trivially_copy_pass_by_ref)]
+    fn successors(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        vec![
+            (x + 1, y + 2),
+            (x + 1, y - 2),
+            (x - 1, y + 2),
+            (x - 1, y - 2),
+            (x + 2, y + 1),
+            (x + 2, y - 1),
+            (x - 2, y + 1),
+            (x - 2, y - 1),
+        ]
+        .into_iter()
+        .filter(|&(x, y)| (0..8).contains(&x) && (0..8).contains(&y))
+        .collect()
+    }
+    for goal in (0..8).cartesian_product(0..8) {
+        let shortest = bfs(&(0, 0), successors, |&p| p == goal).unwrap();
+        let solutions = bfs_bag(&(0, 0), successors, |&p| p == goal)
+            .unwrap()
+            .collect_vec();
+        assert!(solutions.contains(&shortest));
+        assert!(solutions.iter().all(|p| p.len() == shortest.len()));
+        assert!(solutions.iter().all_unique());
+        assert_eq!(
+            count_shortest_paths(&(0, 0), successors, |&p| p == goal, 1usize),
+            solutions.len()
+        );
+    }
+}
+
+#[test]
+fn wide_counts() {
+    // There are C(80, 40) > 2^64 shortest paths from a corner of a 41x41 grid
+    // to the opposite one, which fit in a `u128`.
+    let successors = |&(x, y): &(u32, u32)| {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| x <= 40 && y <= 40)
+    };
+    assert_eq!(
+        count_shortest_paths(&(0, 0), successors, |&p| p == (40, 40), 1u128),
+        107_507_208_733_336_176_461_620
+    );
+    assert_eq!(
+        count_shortest_paths(&(0, 0), successors, |&p| p == (20, 20), 1u64),
+        137_846_528_820
+    );
+}
@@ -0,0 +1,272 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+fn neighbours(graph: &[Vec<usize>]) -> impl FnMut(&usize) -> Vec<usize> + '_ {
+    |&n| graph[n].clone()
+}
+
+fn symmetric(size: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut graph = vec![Vec::new(); size];
+    for &(a, b) in edges {
+        graph[a].push(b);
+        if a != b {
+            graph[b].push(a);
+        }
+    }
+    graph
+}
+
+fn find(parents: &mut [usize], n: usize) -> usize {
+    if parents[n] != n {
+        parents[n] = find(parents, parents[n]);
+    }
+    parents[n]
+}
+
+// Number of connected components, ignoring a node and an edge.
+fn components(size: usize, edges: &[(usize, usize)], node: usize, edge: usize) -> usize {
+    let mut parents = (0..size).collect_vec();
+    for (e, &(a, b)) in edges.iter().enumerate() {
+        if e != edge && a != node && b != node {
+            let (ra, rb) = (find(&mut parents, a), find(&mut parents, b));
+            parents[ra] = rb;
+        }
+    }
+    (0..size)
+        .filter(|&n| n != node && find(&mut parents, n) == n)
+        .count()
+}
+
+#[test]
+fn empty() {
+    let result = biconnectivity(&[], |_: &u32| vec![]);
+    assert!(result.articulation_points.is_empty());
+    assert!(result.bridges.is_empty());
+}
+
+#[test]
+fn path() {
+    let graph = symmetric(4, &[(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(articulation_points(&[0], neighbours(&graph)), vec![1, 2]);
+    assert_eq!(
+        bridges(&[0], neighbours(&graph)),
+        vec![(2, 3), (1, 2), (0, 1)]
+    );
+    // Starting from the middle makes it the root of the search.
+    assert_eq!(articulation_points(&[1], neighbours(&graph)), vec![1, 2]);
+}
+
+#[test]
+fn parallel_edges_and_loops() {
+    let graph = symmetric(3, &[(0, 1), (0, 1), (1, 2), (2, 2)]);
+    assert_eq!(articulation_points(&[0], neighbours(&graph)), vec![1]);
+    assert_eq!(bridges(&[0], neighbours(&graph)), vec![(1, 2)]);
+}
+
+#[test]
+fn cycle() {
+    let graph = symmetric(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    let result = biconnectivity(&[0], neighbours(&graph));
+    assert!(result.articulation_points.is_empty());
+    assert!(result.bridges.is_empty());
+}
+
+#[test]
+fn long_path() {
+    // A recursive search would overflow the stack.
+    let size = 1_000_000;
+    let neighbours = |&n: &usize| {
+        [n.wrapping_sub(1), n + 1]
+            .into_iter()
+            .filter(move |&m| m < size)
+    };
+    let result = biconnectivity(&[0], neighbours);
+    assert_eq!(result.articulation_points.len(), size - 2);
+    assert_eq!(result.bridges.len(), size - 1);
+}
+
+#[test]
+fn components_and_tree() {
+    // Two triangles sharing node 2, and a path 4 - 5 - 6 hanging from node 4.
+    let graph = symmetric(
+        7,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 2),
+            (4, 5),
+            (5, 6),
+        ],
+    );
+    let result = biconnectivity(&[0], neighbours(&graph));
+    assert_eq!(result.articulation_points, vec![2, 4, 5]);
+    assert_eq!(
+        result.components,
+        vec![
+            vec![(5, 6)],
+            vec![(4, 5)],
+            vec![(2, 3), (3, 4), (4, 2)],
+            vec![(0, 1), (1, 2), (2, 0)],
+        ]
+    );
+    assert_eq!(
+        result.two_edge_connected_components,
+        vec![vec![0, 1, 2, 3, 4], vec![5], vec![6]]
+    );
+    assert_eq!(
+        result.block_cut_tree,
+        vec![(0, 5), (1, 4), (1, 5), (2, 2), (2, 4), (3, 2)]
+    );
+}
+
+#[test]
+fn isolated_node() {
+    let graph = symmetric(3, &[(0, 1), (2, 2)]);
+    let result = biconnectivity(&[0, 1, 2], neighbours(&graph));
+    assert_eq!(result.components, vec![vec![(0, 1)]]);
+    assert_eq!(
+        result.two_edge_connected_components,
+        vec![vec![0], vec![1], vec![2]]
+    );
+    assert!(result.block_cut_tree.is_empty());
+}
+
+fn check_components(
+    size: usize,
+    edges: &[(usize, usize)],
+    expected_bridges: &[(usize, usize)],
+    result: &Biconnectivity<usize>,
+) {
+    // Blocks partition the edges which are not loops.
+    assert_eq!(
+        result
+            .components
+            .iter()
+            .flatten()
+            .map(|&(a, b)| (a.min(b), a.max(b)))
+            .sorted()
+            .collect_vec(),
+        edges
+            .iter()
+            .filter(|&&(a, b)| a != b)
+            .map(|&(a, b)| (a.min(b), a.max(b)))
+            .sorted()
+            .collect_vec()
+    );
+    let block_nodes = result
+        .components
+        .iter()
+        .map(|block| {
+            block
+                .iter()
+                .flat_map(|&(a, b)| [a, b])
+                .sorted()
+                .dedup()
+                .collect_vec()
+        })
+        .collect_vec();
+    for (block, nodes) in result.components.iter().zip(&block_nodes) {
+        // A block has no articulation point, and no bridge unless it is a single edge.
+        let inner = symmetric(size, block);
+        let inner_result = biconnectivity(&nodes[..1], neighbours(&inner));
+        assert!(inner_result.articulation_points.is_empty());
+        assert!(block.len() == 1 || inner_result.bridges.is_empty());
+    }
+    // Two blocks share at most an articulation point.
+    for (a, b) in block_nodes.iter().tuple_combinations() {
+        let shared = a.iter().filter(|n| b.contains(n)).collect_vec();
+        assert!(shared.len() <= 1);
+        assert!(
+            shared
+                .iter()
+                .all(|n| result.articulation_points.contains(n))
+        );
+    }
+    let expected_tree = block_nodes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, nodes)| {
+            nodes
+                .iter()
+                .filter(|n| result.articulation_points.contains(n))
+                .map(move |&n| (i, n))
+        })
+        .sorted()
+        .collect_vec();
+    assert_eq!(
+        result.block_cut_tree.iter().copied().sorted().collect_vec(),
+        expected_tree
+    );
+    // Nodes are 2-edge-connected if they are connected without using bridges.
+    let mut parents = (0..size).collect_vec();
+    for (e, &(a, b)) in edges.iter().enumerate() {
+        if !expected_bridges.contains(&(edges[e].0.min(edges[e].1), edges[e].0.max(edges[e].1))) {
+            let (ra, rb) = (find(&mut parents, a), find(&mut parents, b));
+            parents[ra] = rb;
+        }
+    }
+    let expected_components = (0..size)
+        .into_group_map_by(|&n| find(&mut parents, n))
+        .into_values()
+        .sorted()
+        .collect_vec();
+    assert_eq!(
+        result
+            .two_edge_connected_components
+            .iter()
+            .map(|c| c.iter().copied().sorted().collect_vec())
+            .sorted()
+            .collect_vec(),
+        expected_components
+    );
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([13; 16]);
+    for _ in 0..300 {
+        let size = rng.random_range(1..10);
+        let edges = (0..rng.random_range(0..2 * size))
+            .map(|_| (rng.random_range(0..size), rng.random_range(0..size)))
+            .collect_vec();
+        let graph = symmetric(size, &edges);
+        let nodes = (0..size).collect_vec();
+        let result = biconnectivity(&nodes, neighbours(&graph));
+        let initial = components(size, &edges, usize::MAX, usize::MAX);
+        let expected_points = (0..size)
+            .filter(|&n| {
+                components(size, &edges, n, usize::MAX)
+                    > initial - usize::from(graph[n].iter().all(|&m| m == n))
+            })
+            .collect_vec();
+        assert_eq!(
+            result
+                .articulation_points
+                .iter()
+                .copied()
+                .sorted()
+                .collect_vec(),
+            expected_points
+        );
+        let expected_bridges = (0..edges.len())
+            .filter(|&e| components(size, &edges, usize::MAX, e) > initial)
+            .map(|e| (edges[e].0.min(edges[e].1), edges[e].0.max(edges[e].1)))
+            .sorted()
+            .collect_vec();
+        assert_eq!(
+            result
+                .bridges
+                .iter()
+                .map(|&(a, b)| (a.min(b), a.max(b)))
+                .sorted()
+                .collect_vec(),
+            expected_bridges
+        );
+        check_components(size, &edges, &expected_bridges, &result);
+    }
+}
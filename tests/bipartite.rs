@@ -0,0 +1,118 @@
+use itertools::Itertools;
+use pathfinding::prelude::bipartition;
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+fn neighbours(graph: &[Vec<usize>]) -> impl FnMut(&usize) -> Vec<usize> + '_ {
+    |&n| graph[n].clone()
+}
+
+fn linked(graph: &[Vec<usize>], a: usize, b: usize) -> bool {
+    graph[a].contains(&b) || graph[b].contains(&a)
+}
+
+fn check_odd_cycle(graph: &[Vec<usize>], cycle: &[usize]) {
+    assert_eq!(cycle.len() % 2, 1);
+    assert!(cycle.iter().all_unique());
+    assert!(
+        cycle
+            .iter()
+            .circular_tuple_windows()
+            .all(|(&a, &b)| linked(graph, a, b))
+    );
+}
+
+#[test]
+fn empty() {
+    assert_eq!(bipartition(&[], |_: &u32| vec![]), Ok((vec![], vec![])));
+}
+
+#[test]
+fn isolated_nodes() {
+    let graph = [vec![], vec![], vec![]];
+    assert_eq!(
+        bipartition(&[0, 1, 2], neighbours(&graph)),
+        Ok((vec![0, 1, 2], vec![]))
+    );
+}
+
+#[test]
+fn path() {
+    // 0 - 1 - 2 - 3, with edges given in one direction only.
+    let graph = [vec![1], vec![2], vec![3], vec![]];
+    assert_eq!(
+        bipartition(&[2, 0], neighbours(&graph)),
+        Ok((vec![2, 0], vec![3, 1]))
+    );
+}
+
+#[test]
+fn self_loop() {
+    let graph = [vec![1], vec![1]];
+    assert_eq!(bipartition(&[0], neighbours(&graph)), Err(vec![1]));
+}
+
+#[test]
+fn pentagon() {
+    let graph = (0..5).map(|n| vec![(n + 1) % 5, (n + 4) % 5]).collect_vec();
+    let cycle = bipartition(&[0], neighbours(&graph)).unwrap_err();
+    assert_eq!(cycle.len(), 5);
+    check_odd_cycle(&graph, &cycle);
+}
+
+#[test]
+fn several_components() {
+    // A square, then a triangle with a tail.
+    let graph = [
+        vec![1, 3],
+        vec![2],
+        vec![3],
+        vec![],
+        vec![5],
+        vec![6],
+        vec![7],
+        vec![5],
+    ];
+    let cycle = bipartition(&[0, 4], neighbours(&graph)).unwrap_err();
+    assert_eq!(cycle.iter().copied().sorted().collect_vec(), vec![5, 6, 7]);
+    assert_eq!(
+        bipartition(&[0], neighbours(&graph)),
+        Ok((vec![0, 2], vec![1, 3]))
+    );
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([11; 16]);
+    for _ in 0..200 {
+        let size = rng.random_range(1..15);
+        let mut graph = vec![Vec::new(); size];
+        for _ in 0..rng.random_range(0..size + 3) {
+            let (a, b) = (rng.random_range(0..size), rng.random_range(0..size));
+            graph[a].push(b);
+        }
+        // Exhaustive search for a 2-coloring.
+        let colorable = (0..1u32 << size).any(|mask| {
+            (0..size).all(|a| graph[a].iter().all(|&b| (mask >> a) & 1 != (mask >> b) & 1))
+        });
+        let nodes = (0..size).collect_vec();
+        match bipartition(&nodes, neighbours(&graph)) {
+            Ok((first, second)) => {
+                assert!(colorable);
+                assert!(first.iter().chain(&second).copied().sorted().eq(0..size));
+                for side in [&first, &second] {
+                    assert!(
+                        side.iter()
+                            .tuple_combinations()
+                            .all(|(&a, &b)| !linked(&graph, a, b))
+                    );
+                    assert!(side.iter().all(|&a| !graph[a].contains(&a)));
+                }
+            }
+            Err(cycle) => {
+                assert!(!colorable);
+                check_odd_cycle(&graph, &cycle);
+            }
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use itertools::Itertools;
+use pathfinding::prelude::{chinese_postman, undirected_chinese_postman};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+const INF: i64 = i64::MAX / 4;
+
+fn floyd_warshall(size: usize, edges: &[(usize, usize, i64)], directed: bool) -> Vec<Vec<i64>> {
+    let mut dist = vec![vec![INF; size]; size];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[i] = 0;
+    }
+    for &(a, b, c) in edges {
+        dist[a][b] = dist[a][b].min(c);
+        if !directed {
+            dist[b][a] = dist[b][a].min(c);
+        }
+    }
+    for k in 0..size {
+        for i in 0..size {
+            for j in 0..size {
+                dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+            }
+        }
+    }
+    dist
+}
+
+// Minimum cost of pairing all the odd-degree nodes, by exhaustive search.
+fn best_pairing(odd: &[usize], dist: &[Vec<i64>]) -> i64 {
+    let Some((&first, rest)) = odd.split_first() else {
+        return 0;
+    };
+    (0..rest.len())
+        .map(|i| {
+            let mut others = rest.to_vec();
+            let other = others.remove(i);
+            dist[first][other] + best_pairing(&others, dist)
+        })
+        .min()
+        .unwrap()
+}
+
+fn random_undirected(rng: &mut XorShiftRng, size: usize) -> Vec<(usize, usize, i64)> {
+    // A random spanning tree makes the graph connected.
+    let mut edges = (1..size)
+        .map(|n| (rng.random_range(0..n), n, rng.random_range(0..20)))
+        .collect_vec();
+    for _ in 0..rng.random_range(0..2 * size) {
+        let (a, b) = (rng.random_range(0..size), rng.random_range(0..size));
+        edges.push((a, b, rng.random_range(0..20)));
+    }
+    edges
+}
+
+fn check_undirected_walk(edges: &[(usize, usize, i64)], walk: &[(&usize, &usize, i64)], cost: i64) {
+    assert_eq!(walk.iter().map(|w| w.2).sum::<i64>(), cost);
+    if let (Some(first), Some(last)) = (walk.first(), walk.last()) {
+        assert_eq!(first.0, last.1);
+        assert_eq!(*first.0, edges[0].0);
+    }
+    assert!(walk.iter().tuple_windows().all(|(a, b)| a.1 == b.0));
+    let mut traversed = walk
+        .iter()
+        .map(|&(&a, &b, c)| (a.min(b), a.max(b), c))
+        .collect_vec();
+    for &(a, b, c) in edges {
+        let edge = (a.min(b), a.max(b), c);
+        let index = traversed.iter().position(|&e| e == edge).unwrap();
+        traversed.swap_remove(index);
+    }
+}
+
+#[test]
+fn undirected_eulerian_graph() {
+    // Every node has an even degree: no edge is traversed twice.
+    let edges = [(1, 2, 1), (2, 3, 2), (3, 1, 3)];
+    let (walk, cost) = undirected_chinese_postman(&edges).unwrap();
+    assert_eq!(cost, 6);
+    assert_eq!(walk, vec![(&1, &2, 1), (&2, &3, 2), (&3, &1, 3)]);
+}
+
+#[test]
+fn undirected_path_graph() {
+    // 1 - 2 - 3: every edge is traversed twice.
+    let edges = [(1, 2, 4), (2, 3, 5)];
+    let (walk, cost) = undirected_chinese_postman(&edges).unwrap();
+    assert_eq!(cost, 18);
+    assert_eq!(
+        walk,
+        vec![(&1, &2, 4), (&2, &3, 5), (&3, &2, 5), (&2, &1, 4)]
+    );
+}
+
+#[test]
+fn undirected_pairing() {
+    // 0 - 1 - 2 - 3 on a line with a triangle attached at each end.
+    let edges = [
+        (0, 1, 2),
+        (1, 2, 1),
+        (2, 3, 2),
+        (0, 4, 10),
+        (4, 5, 10),
+        (5, 0, 10),
+        (3, 6, 10),
+        (6, 7, 10),
+        (7, 3, 10),
+    ];
+    // Odd-degree nodes are 0 and 3 only, and the line between them is duplicated.
+    let (walk, cost) = undirected_chinese_postman(&edges).unwrap();
+    assert_eq!(cost, 65 + 5);
+    check_undirected_walk(&edges, &walk, cost);
+}
+
+#[test]
+fn undirected_disconnected() {
+    let edges = [(1, 2, 1), (3, 4, 1)];
+    assert_eq!(undirected_chinese_postman(&edges), None);
+    let edges = [(1, 2, 1), (2, 1, 1), (3, 4, 1), (4, 3, 1)];
+    assert_eq!(undirected_chinese_postman(&edges), None);
+}
+
+#[test]
+fn undirected_no_edges() {
+    assert_eq!(
+        undirected_chinese_postman::<u32, i32>(&[]),
+        Some((vec![], 0))
+    );
+}
+
+#[test]
+fn undirected_random() {
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    for _ in 0..200 {
+        let size = rng.random_range(1..12);
+        let edges = random_undirected(&mut rng, size);
+        let result = undirected_chinese_postman(&edges);
+        if edges.is_empty() {
+            assert_eq!(result, Some((vec![], 0)));
+            continue;
+        }
+        let (walk, cost) = result.unwrap();
+        check_undirected_walk(&edges, &walk, cost);
+        let mut degrees = vec![0; size];
+        for &(a, b, _) in &edges {
+            degrees[a] += 1;
+            degrees[b] += 1;
+        }
+        let odd = (0..size).filter(|&n| degrees[n] % 2 == 1).collect_vec();
+        let dist = floyd_warshall(size, &edges, false);
+        let expected = edges.iter().map(|e| e.2).sum::<i64>() + best_pairing(&odd, &dist);
+        assert_eq!(cost, expected);
+    }
+}
+
+fn successors(graph: &[Vec<(usize, i64)>]) -> impl FnMut(&usize) -> Vec<(usize, i64)> + '_ {
+    |&n| graph[n].clone()
+}
+
+#[test]
+fn directed_eulerian_graph() {
+    let graph = [vec![(1, 1)], vec![(2, 2)], vec![(0, 3)]];
+    let (walk, cost) = chinese_postman(&[0], successors(&graph)).unwrap();
+    assert_eq!(walk, vec![0, 1, 2, 0]);
+    assert_eq!(cost, 6);
+}
+
+#[test]
+fn directed_not_strongly_connected() {
+    let graph = [vec![(1, 1)], vec![]];
+    assert_eq!(chinese_postman(&[0], successors(&graph)), None);
+    let graph = [vec![(0, 1)], vec![(1, 1)]];
+    assert_eq!(chinese_postman(&[0, 1], successors(&graph)), None);
+}
+
+#[test]
+fn directed_no_edges() {
+    let graph = [vec![], vec![]];
+    assert_eq!(
+        chinese_postman(&[0, 1], successors(&graph)),
+        Some((vec![], 0))
+    );
+}
+
+#[test]
+fn directed_random() {
+    let mut rng = XorShiftRng::from_seed([6; 16]);
+    for _ in 0..200 {
+        // A random cycle through all the nodes makes the graph strongly connected.
+        let size = rng.random_range(1..9);
+        let mut order = (0..size).collect_vec();
+        for i in 1..size {
+            order.swap(i, rng.random_range(0..=i));
+        }
+        let mut edges = order
+            .iter()
+            .circular_tuple_windows()
+            .map(|(&a, &b)| (a, b, rng.random_range(0..20)))
+            .collect_vec();
+        for _ in 0..rng.random_range(0..size) {
+            let (a, b) = (rng.random_range(0..size), rng.random_range(0..size));
+            edges.push((a, b, rng.random_range(0..20)));
+        }
+        let mut graph = vec![Vec::new(); size];
+        for &(a, b, c) in &edges {
+            graph[a].push((b, c));
+        }
+        let (walk, cost) = chinese_postman(&[0], successors(&graph)).unwrap();
+        assert_eq!(walk.first(), walk.last());
+        // Every edge is traversed, and the cost matches the cheapest edges taken.
+        let mut steps = walk
+            .iter()
+            .tuple_windows()
+            .map(|(&a, &b)| (a, b))
+            .collect_vec();
+        let mut total = 0;
+        for &(a, b, c) in &edges {
+            let index = steps.iter().position(|&s| s == (a, b)).unwrap();
+            steps.swap_remove(index);
+            total += c;
+        }
+        for (a, b) in steps {
+            total += graph[a]
+                .iter()
+                .filter(|s| s.0 == b)
+                .map(|s| s.1)
+                .min()
+                .unwrap();
+        }
+        assert_eq!(cost, total);
+        // Compare with the best assignment of nodes with missing outgoing edges
+        // to nodes with missing incoming edges.
+        let mut balances = vec![0i64; size];
+        for &(a, b, _) in &edges {
+            balances[a] += 1;
+            balances[b] -= 1;
+        }
+        let starts = (0..size)
+            .flat_map(|n| std::iter::repeat_n(n, usize::try_from(-balances[n].min(0)).unwrap()))
+            .collect_vec();
+        let ends = (0..size)
+            .flat_map(|n| std::iter::repeat_n(n, usize::try_from(balances[n].max(0)).unwrap()))
+            .collect_vec();
+        let dist = floyd_warshall(size, &edges, true);
+        let extra = ends
+            .iter()
+            .permutations(ends.len())
+            .map(|p| starts.iter().zip(p).map(|(&s, &e)| dist[s][e]).sum::<i64>())
+            .min()
+            .unwrap();
+        assert_eq!(cost, edges.iter().map(|e| e.2).sum::<i64>() + extra);
+    }
+}
@@ -0,0 +1,144 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+fn is_proper(size: usize, edges: &[Vec<bool>], colors: &HashMap<usize, usize>) -> bool {
+    colors.len() == size
+        && (0..size)
+            .tuple_combinations()
+            .all(|(a, b)| !edges[a][b] || colors[&a] != colors[&b])
+}
+
+fn colors_used(colors: &HashMap<usize, usize>) -> usize {
+    colors.values().max().map_or(0, |&c| c + 1)
+}
+
+// Smallest number of colors, by exhaustive search.
+fn chromatic_number(size: usize, edges: &[Vec<bool>]) -> usize {
+    (0..=size)
+        .find(|&k| {
+            (0..size).map(|_| 0..k).multi_cartesian_product().any(|c| {
+                (0..size)
+                    .tuple_combinations()
+                    .all(|(a, b)| !edges[a][b] || c[a] != c[b])
+            })
+        })
+        .unwrap()
+}
+
+fn random_graph(rng: &mut XorShiftRng, size: usize) -> Vec<Vec<bool>> {
+    let mut edges = vec![vec![false; size]; size];
+    for (a, b) in (0..size).tuple_combinations() {
+        let linked = rng.random_ratio(2, 5);
+        edges[a][b] = linked;
+        edges[b][a] = linked;
+    }
+    edges
+}
+
+#[test]
+fn empty() {
+    let mut connected = |_: &u32, _: &u32| true;
+    assert!(greedy_coloring([], &mut connected).is_empty());
+    assert!(welsh_powell_coloring([], &mut connected).is_empty());
+    assert!(dsatur_coloring([], &mut connected).is_empty());
+    assert_eq!(
+        k_coloring([], &mut connected, 0, 0),
+        KColoring::Colorable(HashMap::new())
+    );
+    assert_eq!(
+        k_coloring([1], &mut connected, 0, 10),
+        KColoring::NotColorable
+    );
+}
+
+#[test]
+fn complete_graph() {
+    let mut connected = |a: &u32, b: &u32| a != b;
+    for colors in [
+        greedy_coloring(0..5, &mut connected),
+        welsh_powell_coloring(0..5, &mut connected),
+        dsatur_coloring(0..5, &mut connected),
+    ] {
+        assert_eq!(
+            colors.values().copied().sorted().collect_vec(),
+            (0..5).collect_vec()
+        );
+    }
+    assert_eq!(
+        k_coloring(0..5, &mut connected, 4, 1000),
+        KColoring::NotColorable
+    );
+    assert!(matches!(
+        k_coloring(0..5, &mut connected, 5, 1000),
+        KColoring::Colorable(_)
+    ));
+}
+
+#[test]
+fn crown_graph() {
+    // Two rows of four vertices, each one connected to all the vertices of the
+    // other row except the one facing it. Greedy coloring alternating between
+    // the rows uses four colors, while two are enough.
+    let order = [0, 4, 1, 5, 2, 6, 3, 7];
+    let mut connected = |&a: &usize, &b: &usize| a / 4 != b / 4 && a % 4 != b % 4;
+    assert_eq!(colors_used(&greedy_coloring(order, &mut connected)), 4);
+    assert_eq!(colors_used(&dsatur_coloring(order, &mut connected)), 2);
+}
+
+#[test]
+fn budget() {
+    // Proving that a complete graph is not colorable is long without a
+    // large enough budget.
+    let mut connected = |a: &u32, b: &u32| a != b;
+    assert_eq!(
+        k_coloring(0..8, &mut connected, 7, 5),
+        KColoring::BudgetExceeded
+    );
+}
+
+#[test]
+fn long_path() {
+    let mut connected = |a: &u32, b: &u32| a.abs_diff(*b) == 1;
+    let KColoring::Colorable(colors) = k_coloring(0..3000, &mut connected, 2, 3000) else {
+        panic!("a path must be 2-colorable");
+    };
+    assert!((1..3000).all(|n| colors[&n] != colors[&(n - 1)]));
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([12; 16]);
+    for _ in 0..100 {
+        let size = rng.random_range(1..8);
+        let edges = random_graph(&mut rng, size);
+        let mut connected = |&a: &usize, &b: &usize| edges[a][b];
+        let chromatic = chromatic_number(size, &edges);
+        let max_degree = (0..size)
+            .map(|a| edges[a].iter().filter(|&&e| e).count())
+            .max()
+            .unwrap();
+        for colors in [
+            greedy_coloring(0..size, &mut connected),
+            welsh_powell_coloring(0..size, &mut connected),
+            dsatur_coloring(0..size, &mut connected),
+        ] {
+            assert!(is_proper(size, &edges, &colors));
+            assert!(colors_used(&colors) >= chromatic);
+            assert!(colors_used(&colors) <= max_degree + 1);
+        }
+        assert_eq!(
+            k_coloring(0..size, &mut connected, chromatic - 1, 1_000_000),
+            KColoring::NotColorable
+        );
+        let KColoring::Colorable(colors) =
+            k_coloring(0..size, &mut connected, chromatic, 1_000_000)
+        else {
+            panic!("graph should be colorable");
+        };
+        assert!(is_proper(size, &edges, &colors));
+        assert!(colors_used(&colors) <= chromatic);
+    }
+}
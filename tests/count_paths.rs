@@ -1,4 +1,4 @@
-use pathfinding::directed::count_paths::count_paths;
+use pathfinding::directed::count_paths::{count_paths, try_count_paths};
 
 #[test]
 fn grid() {
@@ -13,3 +13,99 @@ fn grid() {
     );
     assert_eq!(n, 3432);
 }
+
+#[test]
+fn generic_count() {
+    let n = try_count_paths(
+        (0, 0),
+        |&(x, y)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x < 60 && y < 60)
+        },
+        |&c| c == (59, 59),
+        1u128,
+    );
+    // Binomial coefficient (118, 59)
+    assert_eq!(n, Ok(24_356_699_707_654_619_143_838_606_602_026_720));
+}
+
+#[test]
+fn modular_count() {
+    use std::ops::Add;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Mod(u64);
+    const MOD: u64 = 1_000_000_007;
+    impl Add for Mod {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Self((self.0 + rhs.0) % MOD)
+        }
+    }
+    impl num_traits::Zero for Mod {
+        fn zero() -> Self {
+            Self(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    // Fibonacci numbers: paths from n to 0 by steps of 1 or 2.
+    let n = try_count_paths(
+        1000u32,
+        |&n| [n.checked_sub(1), n.checked_sub(2)].into_iter().flatten(),
+        |&n| n == 0,
+        Mod(1),
+    );
+    // F(1001) mod 1_000_000_007
+    assert_eq!(n, Ok(Mod(107_579_939)));
+}
+
+#[test]
+fn long_chain() {
+    const LEN: usize = 1_000_000;
+    let n = count_paths(
+        0,
+        |&n| if n < LEN { vec![n + 1] } else { vec![] },
+        |&n| n == LEN,
+    );
+    assert_eq!(n, 1);
+}
+
+#[test]
+fn cycle_detected() {
+    // 0 -> 1 -> 2 -> 3 -> 1 (loop), 2 -> 4 (goal)
+    let successors = |&n: &u32| match n {
+        0 | 3 => vec![1],
+        1 => vec![2],
+        2 => vec![3, 4],
+        _ => vec![],
+    };
+    let err = try_count_paths(0, successors, |&n| n == 4, 1usize).unwrap_err();
+    assert!([1, 2, 3].contains(&err));
+}
+
+#[test]
+fn cycle_behind_goal_is_ignored() {
+    // The loop 2 -> 3 -> 2 is only reachable through the goal 2.
+    let successors = |&n: &u32| match n {
+        0 => vec![1, 2],
+        1 | 3 => vec![2],
+        2 => vec![3],
+        _ => vec![],
+    };
+    assert_eq!(try_count_paths(0, successors, |&n| n == 2, 1u8), Ok(2));
+}
+
+#[test]
+fn start_is_goal() {
+    assert_eq!(count_paths(0, |&n| vec![n], |&n| n == 0), 1);
+}
+
+#[test]
+#[should_panic(expected = "loop")]
+fn cycle_panics() {
+    count_paths(0, |&n| vec![(n + 1) % 3], |&n| n == 5);
+}
@@ -134,3 +134,78 @@ fn test_short_cycle_large_mu() {
     assert!(mu_tilde_floyd < mu_floyd + lam_floyd);
     assert!(mu_brent <= mu_tilde_brent);
 }
+
+#[test]
+fn hash_cycle_works() {
+    assert_eq!(hash_cycle(-10, |x| (x + 5) % 6 + 3), (3, 6, 2));
+    assert_eq!(hash_cycle(0, |x: i32| (x + 1) % 100), (100, 0, 0));
+    assert_eq!(hash_cycle(5, |x| x), (1, 5, 0));
+}
+
+#[test]
+fn hash_cycle_matches_brent() {
+    for start in -20..20 {
+        let f = |x: i64| (x * x + 3) % 1009;
+        assert_eq!(hash_cycle(start, f), brent(start, f));
+    }
+}
+
+#[test]
+fn hash_cycle_calls_successor_once_per_state() {
+    let mut calls = 0;
+    let result = hash_cycle(-10, |x| {
+        calls += 1;
+        (x + 5) % 6 + 3
+    });
+    assert_eq!(result, (3, 6, 2));
+    // 5 distinct states, and one more call to find the repeated one.
+    assert_eq!(calls, 5);
+}
+
+#[test]
+fn hash_cycle_by_key_ignores_extra_data() {
+    let (lam, first, mu) = hash_cycle_by_key(
+        (-10, "start"),
+        |(x, _)| ((x + 5) % 6 + 3, "next"),
+        |&(x, _)| x,
+    );
+    assert_eq!((lam, first, mu), (3, (6, "next"), 2));
+}
+
+#[test]
+fn nth_state_works() {
+    // Sequence: -10, -2, 6, 8, 4, 6, 8, 4, ...
+    let f = |x| (x + 5) % 6 + 3;
+    let expected = [-10, -2, 6, 8, 4, 6, 8, 4, 6, 8];
+    for (n, e) in expected.into_iter().enumerate() {
+        assert_eq!(nth_state(-10, f, n), e);
+    }
+    assert_eq!(nth_state(-10, f, 1_000_000_000_000), 4);
+    assert_eq!(
+        nth_state(-10, f, usize::MAX),
+        [6, 8, 4][(usize::MAX - 2) % 3]
+    );
+}
+
+#[test]
+fn nth_state_stops_early() {
+    // The sequence never repeats, but the requested state is computed anyway.
+    let mut calls = 0;
+    let state = nth_state(
+        0u64,
+        |x| {
+            calls += 1;
+            x + 1
+        },
+        10,
+    );
+    assert_eq!(state, 10);
+    assert_eq!(calls, 10);
+}
+
+#[test]
+fn nth_state_by_key_works() {
+    let state = nth_state_by_key((1, 0), |(x, n)| (x * 2 % 7, n + 1), |&(x, _)| x, 100);
+    // 100 % 3 == 1, and the second element of the cycle was first seen at index 1.
+    assert_eq!(state, (2, 1));
+}
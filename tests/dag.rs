@@ -0,0 +1,162 @@
+use pathfinding::prelude::*;
+use rand::{RngExt as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+// Random DAG whose edges always go from a smaller to a larger node.
+fn random_dag(size: usize, seed: u64) -> Vec<Vec<(usize, i64)>> {
+    let mut rng = XorShiftRng::seed_from_u64(seed);
+    let mut dag = vec![vec![]; size];
+    for (a, succs) in dag.iter_mut().enumerate() {
+        for b in a + 1..size {
+            if rng.random_ratio(1, 4) {
+                succs.push((b, rng.random_range(0..100)));
+            }
+        }
+    }
+    dag
+}
+
+#[test]
+fn shortest_matches_dijkstra() {
+    for seed in 0..10 {
+        let dag = random_dag(50, seed);
+        let paths = dag_shortest_paths(&0, |&n| dag[n].clone()).unwrap();
+        let reference = dijkstra_all(&0, |&n| dag[n].clone());
+        assert_eq!(paths.len(), reference.len());
+        for (node, (_, cost)) in &reference {
+            assert_eq!(paths[node].1, *cost);
+        }
+        for node in paths.keys() {
+            let path = build_path(node, &paths);
+            assert_eq!(path[0], 0);
+            let cost = path
+                .windows(2)
+                .map(|w| dag[w[0]].iter().find(|(n, _)| *n == w[1]).unwrap().1)
+                .sum::<i64>();
+            assert_eq!(cost, paths[node].1);
+        }
+    }
+}
+
+#[test]
+fn longest_is_negated_shortest() {
+    for seed in 0..10 {
+        let dag = random_dag(50, seed);
+        let longest = dag_longest_paths(&0, |&n| dag[n].clone()).unwrap();
+        let shortest = dag_shortest_paths(&0, |&n| {
+            dag[n].iter().map(|&(s, c)| (s, -c)).collect::<Vec<_>>()
+        })
+        .unwrap();
+        assert_eq!(longest.len(), shortest.len());
+        for (node, (_, cost)) in &longest {
+            assert_eq!(*cost, -shortest[node].1);
+        }
+    }
+}
+
+#[test]
+fn successors_requested_once() {
+    let mut requested = 0;
+    let paths = dag_longest_paths(&0, |&n: &u32| {
+        requested += 1;
+        if n < 100 {
+            vec![(n + 1, 1), (n + 2, 3)]
+        } else {
+            vec![]
+        }
+    })
+    .unwrap();
+    assert_eq!(requested, 102);
+    assert_eq!(paths[&100].1, 150);
+}
+
+#[test]
+fn cycle_detected() {
+    let result = dag_shortest_paths(&0, |&n: &u32| vec![((n + 1) % 4, 1)]);
+    assert!(result.is_err());
+    let result = dag_longest_paths(&0, |&n: &u32| vec![((n + 1) % 4, 1)]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn single_node() {
+    let paths = dag_shortest_paths(&0, |_: &u32| Vec::<(u32, u32)>::new()).unwrap();
+    assert!(paths.is_empty());
+}
+
+#[test]
+fn critical_path_analysis() {
+    //       B(2) -> D(4)
+    //     /             \
+    // A(3)               F(1)
+    //     \             /
+    //       C(1) -> E(2)
+    let tasks = ['a', 'b', 'c', 'd', 'e', 'f'];
+    let duration = |&t: &char| match t {
+        'a' => 3,
+        'b' | 'e' => 2,
+        'd' => 4,
+        _ => 1,
+    };
+    let dependencies = |&t: &char| match t {
+        'b' | 'c' => vec!['a'],
+        'd' => vec!['b'],
+        'e' => vec!['c'],
+        'f' => vec!['d', 'e'],
+        _ => vec![],
+    };
+    let analysis = critical_path(&tasks, duration, dependencies).unwrap();
+    assert_eq!(analysis.duration, 10);
+    assert_eq!(analysis.path, vec!['a', 'b', 'd', 'f']);
+    let expected = [
+        ('a', 0, 0),
+        ('b', 3, 3),
+        ('c', 3, 6),
+        ('d', 5, 5),
+        ('e', 4, 7),
+        ('f', 9, 9),
+    ];
+    assert_eq!(analysis.timings.len(), expected.len());
+    for (task, earliest_start, latest_start) in expected {
+        assert_eq!(
+            analysis.timings[&task],
+            TaskTiming {
+                earliest_start,
+                latest_start,
+                slack: latest_start - earliest_start,
+            }
+        );
+    }
+}
+
+#[test]
+fn critical_path_independent_tasks() {
+    let analysis = critical_path(&[1, 2, 3], |&t| t * 10, |_| vec![]).unwrap();
+    assert_eq!(analysis.duration, 30);
+    assert_eq!(analysis.path, vec![3]);
+    assert_eq!(analysis.timings[&1].slack, 20);
+    assert_eq!(analysis.timings[&2].slack, 10);
+    assert_eq!(analysis.timings[&3].slack, 0);
+}
+
+#[test]
+fn critical_path_discovered_tasks() {
+    // Task 1 is not listed but is a dependency of task 2.
+    let analysis = critical_path(&[2], |_| 5, |&t| if t == 2 { vec![1] } else { vec![] }).unwrap();
+    assert_eq!(analysis.duration, 10);
+    assert_eq!(analysis.path, vec![1, 2]);
+}
+
+#[test]
+fn critical_path_empty() {
+    let analysis = critical_path(&[], |_: &u32| 1, |_| vec![]).unwrap();
+    assert_eq!(analysis.duration, 0);
+    assert!(analysis.path.is_empty());
+    assert!(analysis.timings.is_empty());
+}
+
+#[test]
+fn critical_path_cycle() {
+    let result = critical_path(&[1, 2], |_| 1, |&t| vec![3 - t]);
+    assert!(result.is_err());
+}
@@ -0,0 +1,92 @@
+use itertools::Itertools;
+use pathfinding::prelude::*;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    // 1 --> 2 --> 4
+    //   --> 3 --> 4
+    //
+    // 2 --> 5 --> 6 --> 7
+    // 3 --> 5 --> 6 --> 7
+    match n {
+        1 => vec![(2, 1), (3, 1)],
+        2 | 3 => vec![(4, 3), (5, 1)],
+        5 => vec![(6, 1)],
+        6 => vec![(7, 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn multiple_sinks() {
+    let (solutions, cost) = dijkstra_bag(&1, successors, |&n| n == 4 || n == 7).unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(
+        solutions.sorted().collect_vec(),
+        vec![
+            vec![1, 2, 4],
+            vec![1, 2, 5, 6, 7],
+            vec![1, 3, 4],
+            vec![1, 3, 5, 6, 7],
+        ]
+    );
+}
+
+#[test]
+fn collect() {
+    let (mut solutions, cost) = dijkstra_bag_collect(&1, successors, |&n| n == 6).unwrap();
+    solutions.sort();
+    assert_eq!(cost, 3);
+    assert_eq!(solutions, vec![vec![1, 2, 5, 6], vec![1, 3, 5, 6]]);
+}
+
+#[test]
+fn no_path() {
+    assert!(dijkstra_bag(&4, successors, |&n| n == 1).is_none());
+    assert!(dijkstra_bag_collect(&4, successors, |&n| n == 1).is_none());
+}
+
+#[test]
+fn same_as_dijkstra() {
+    for target in 1..=7 {
+        let expected = dijkstra(&1, successors, |&n| n == target).map(|(_, c)| c);
+        let (solutions, cost) = dijkstra_bag_collect(&1, successors, |&n| n == target).unwrap();
+        assert_eq!(Some(cost), expected);
+        assert!(solutions.iter().all(|p| p.last() == Some(&target)));
+    }
+}
+
+#[test]
+fn all_shortest_parents() {
+    let mut parents = dijkstra_all_shortest_parents(&1, successors);
+    for (ps, _) in parents.values_mut() {
+        ps.sort_unstable();
+    }
+    assert_eq!(parents.len(), 6);
+    assert_eq!(parents[&2], (vec![1], 1));
+    assert_eq!(parents[&3], (vec![1], 1));
+    assert_eq!(parents[&4], (vec![2, 3], 4));
+    assert_eq!(parents[&5], (vec![2, 3], 2));
+    assert_eq!(parents[&6], (vec![5], 3));
+    assert_eq!(parents[&7], (vec![6], 4));
+}
+
+#[test]
+fn all_shortest_parents_consistent_with_dijkstra_all() {
+    let all = dijkstra_all(&1, successors);
+    let shortest = dijkstra_all_shortest_parents(&1, successors);
+    assert_eq!(all.len(), shortest.len());
+    for (node, (parent, cost)) in all {
+        let (parents, c) = &shortest[&node];
+        assert_eq!(*c, cost);
+        assert!(parents.contains(&parent));
+    }
+}
+
+#[test]
+fn all_shortest_parents_ignores_start() {
+    let parents = dijkstra_all_shortest_parents(&1, |&n: &u32| vec![((n + 1) % 3, 0)]);
+    assert_eq!(parents.len(), 2);
+    assert_eq!(parents[&2], (vec![1], 0));
+    assert_eq!(parents[&0], (vec![2], 0));
+}
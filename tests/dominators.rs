@@ -0,0 +1,108 @@
+use itertools::Itertools;
+use pathfinding::prelude::{bfs_reach, dominators};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+// Check whether every path from 0 to `b` goes through `a` by removing `a`
+// from the graph.
+fn brute_force_dominates(graph: &[Vec<usize>], a: usize, b: usize) -> bool {
+    a == b
+        || a == 0
+        || !bfs_reach(0, |&n| {
+            graph[n].iter().copied().filter(|&s| s != a).collect_vec()
+        })
+        .contains(&b)
+}
+
+#[test]
+fn single_node() {
+    let dom = dominators(&0, |_| vec![]);
+    assert_eq!(dom.root(), &0);
+    assert_eq!(dom.immediate_dominator(&0), None);
+    assert!(dom.dominates(&0, &0));
+    assert_eq!(dom.dominators(&0).collect_vec(), vec![&0]);
+    assert_eq!(dom.children(&0).count(), 0);
+    assert_eq!(dom.dominance_frontiers()[&0], Vec::<usize>::new());
+}
+
+#[test]
+fn cooper_harvey_kennedy_example() {
+    // Figure 4 from the paper, with 6 being the root.
+    let succs: &[&[usize]] = &[&[], &[2], &[1, 3], &[2], &[2, 3], &[1], &[4, 5]];
+    let dom = dominators(&6, |&n| succs[n].iter().copied());
+    for n in 1..=5 {
+        assert_eq!(dom.immediate_dominator(&n), Some(&6));
+    }
+    assert!(!dom.is_reachable(&0));
+    assert_eq!(dom.immediate_dominator(&0), None);
+    assert!(!dom.dominates(&6, &0));
+    assert_eq!(dom.dominators(&0).count(), 0);
+    assert_eq!(
+        dom.children(&6).sorted().collect_vec(),
+        vec![&1, &2, &3, &4, &5]
+    );
+}
+
+#[test]
+fn deep_graph() {
+    // A long chain must not overflow the stack.
+    let dom = dominators(&0, |&n: &usize| (n < 100_000).then_some(n + 1));
+    assert_eq!(dom.immediate_dominator(&100_000), Some(&99_999));
+    assert!(dom.dominates(&1, &100_000));
+    assert!(!dom.dominates(&100_000, &1));
+    assert_eq!(dom.dominators(&100_000).count(), 100_001);
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([6; 16]);
+    for _ in 0..30 {
+        let size = 25;
+        let graph = (0..size)
+            .map(|_| (0..size).filter(|_| rng.random_ratio(1, 10)).collect_vec())
+            .collect_vec();
+        let mut requested = 0;
+        let dom = dominators(&0, |&n| {
+            requested += 1;
+            graph[n].clone()
+        });
+        let reachable = bfs_reach(0, |&n| graph[n].clone()).collect_vec();
+        assert_eq!(requested, reachable.len());
+        let frontiers = dom.dominance_frontiers();
+        assert_eq!(frontiers.len(), reachable.len());
+        for n in 0..size {
+            assert_eq!(dom.is_reachable(&n), reachable.contains(&n));
+        }
+        for &a in &reachable {
+            for &b in &reachable {
+                let dominates = brute_force_dominates(&graph, a, b);
+                assert_eq!(dom.dominates(&a, &b), dominates, "{a} dominates {b}");
+                assert_eq!(dom.dominators(&b).contains(&a), dominates);
+                assert_eq!(
+                    dom.children(&a).contains(&b),
+                    dom.immediate_dominator(&b) == Some(&a)
+                );
+            }
+            // The immediate dominator is the strict dominator dominated by all others.
+            if let Some(&idom) = dom.immediate_dominator(&a) {
+                assert!(dom.dominators(&a).skip(1).all(|d| dom.dominates(d, &idom)));
+            }
+            // Check the frontier against its definition.
+            let expected = reachable
+                .iter()
+                .copied()
+                .filter(|&b| {
+                    reachable
+                        .iter()
+                        .any(|&p| graph[p].contains(&b) && dom.dominates(&a, &p))
+                        && (a == b || !dom.dominates(&a, &b))
+                })
+                .sorted()
+                .collect_vec();
+            assert_eq!(
+                frontiers[&a].iter().copied().sorted().collect_vec(),
+                expected
+            );
+        }
+    }
+}
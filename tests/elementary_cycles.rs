@@ -0,0 +1,110 @@
+use itertools::Itertools;
+use pathfinding::prelude::{elementary_cycles, elementary_cycles_with_length};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+// Enumerate cycles starting with their smallest node by exploring all paths.
+fn brute_force(graph: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    fn explore(graph: &[Vec<usize>], path: &mut Vec<usize>, cycles: &mut Vec<Vec<usize>>) {
+        let (start, last) = (path[0], path[path.len() - 1]);
+        for &s in &graph[last] {
+            if s == start {
+                cycles.push(path.clone());
+            } else if s > start && !path.contains(&s) {
+                path.push(s);
+                explore(graph, path, cycles);
+                path.pop();
+            }
+        }
+    }
+    let mut cycles = Vec::new();
+    for start in 0..graph.len() {
+        explore(graph, &mut vec![start], &mut cycles);
+    }
+    cycles
+}
+
+#[test]
+fn empty() {
+    assert_eq!(elementary_cycles(&[], |_: &usize| vec![]).count(), 0);
+    assert_eq!(elementary_cycles(&[1, 2], |_| vec![]).count(), 0);
+}
+
+#[test]
+fn dag_has_no_cycles() {
+    let cycles = elementary_cycles(&[0], |&n: &usize| (n + 1..10).collect_vec());
+    assert_eq!(cycles.count(), 0);
+}
+
+#[test]
+fn complete_graph() {
+    // The number of elementary cycles in a complete directed graph on 6 nodes
+    // is the sum of C(6, k) * (k - 1)! for k in 2..=6.
+    let nodes = (0..6).collect_vec();
+    let successors = |&n: &usize| (0..6).filter(|&m| m != n).collect_vec();
+    assert_eq!(elementary_cycles(&nodes, successors).count(), 409);
+    let triangles = elementary_cycles_with_length(&nodes, successors, 3..=3).collect_vec();
+    assert_eq!(triangles.len(), 40);
+    assert!(
+        triangles
+            .iter()
+            .all(|c| c.len() == 3 && c.iter().all_unique())
+    );
+}
+
+#[test]
+fn duplicate_edges() {
+    let cycles = elementary_cycles(&['a'], |&n| match n {
+        'a' => vec!['b', 'b'],
+        _ => vec!['a', 'a', 'b'],
+    })
+    .collect_vec();
+    assert_eq!(cycles, vec![vec!['a', 'b'], vec!['b']]);
+}
+
+#[test]
+fn discovered_nodes() {
+    let cycles = elementary_cycles(&[0], |&n: &usize| [(n + 1) % 1000]).collect_vec();
+    assert_eq!(cycles, vec![(0..1000).collect_vec()]);
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([9; 16]);
+    for _ in 0..50 {
+        let size = 9;
+        let graph = (0..size)
+            .map(|_| (0..size).filter(|_| rng.random_ratio(1, 4)).collect_vec())
+            .collect_vec();
+        let nodes = (0..size).collect_vec();
+        let expected = brute_force(&graph).into_iter().sorted().collect_vec();
+        let cycles = elementary_cycles(&nodes, |&n| graph[n].clone()).collect_vec();
+        // Cycles are grouped by their first node.
+        assert!(cycles.iter().map(|c| c[0]).is_sorted());
+        assert_eq!(cycles.into_iter().sorted().collect_vec(), expected);
+        for (min, max) in [(1, 1), (2, 3), (3, 5), (4, 9)] {
+            let bounded = elementary_cycles_with_length(&nodes, |&n| graph[n].clone(), min..=max)
+                .sorted()
+                .collect_vec();
+            let filtered = expected
+                .iter()
+                .filter(|c| (min..=max).contains(&c.len()))
+                .cloned()
+                .collect_vec();
+            assert_eq!(bounded, filtered, "lengths {min}..={max}");
+        }
+    }
+}
+
+#[test]
+fn long_chain_with_final_cycle() {
+    // Nodes with no cycle through them are skipped at once instead of being
+    // examined one by one.
+    let size = 100_000;
+    let successors = |&n: &usize| match n {
+        n if n == size - 1 => vec![n - 1],
+        n => vec![n + 1],
+    };
+    let cycles = elementary_cycles(&[0], successors).collect::<Vec<_>>();
+    assert_eq!(cycles, vec![vec![size - 2, size - 1]]);
+}
@@ -0,0 +1,230 @@
+use itertools::Itertools;
+use pathfinding::prelude::{
+    EulerianError, eulerian_circuit, eulerian_path, undirected_eulerian_circuit,
+    undirected_eulerian_path,
+};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+// Check that `trail` uses every edge of `graph` exactly once.
+fn check_directed(graph: &[Vec<usize>], trail: &[usize]) {
+    let mut used = trail
+        .iter()
+        .tuple_windows()
+        .map(|(&a, &b)| (a, b))
+        .collect_vec();
+    let mut edges = graph
+        .iter()
+        .enumerate()
+        .flat_map(|(a, succs)| succs.iter().map(move |&b| (a, b)))
+        .collect_vec();
+    used.sort_unstable();
+    edges.sort_unstable();
+    assert_eq!(used, edges);
+}
+
+#[test]
+fn directed_circuit() {
+    let graph = [vec![1, 2], vec![2], vec![0, 3], vec![0]];
+    let circuit = eulerian_circuit(&[0], |&n| graph[n].clone()).unwrap();
+    assert_eq!(circuit, vec![0, 1, 2, 0, 2, 3, 0]);
+    check_directed(&graph, &circuit);
+    let path = eulerian_path(&[0], |&n| graph[n].clone()).unwrap();
+    assert_eq!(path, circuit);
+}
+
+#[test]
+fn directed_path() {
+    // 0 -> 1 -> 2 -> 0, 2 -> 3
+    let graph = [vec![1], vec![2], vec![0, 3], vec![]];
+    let path = eulerian_path(&[3, 0], |&n| graph[n].clone()).unwrap();
+    assert_eq!(path, vec![2, 0, 1, 2, 3]);
+    assert_eq!(
+        eulerian_circuit(&[0], |&n| graph[n].clone()),
+        Err(EulerianError::Unbalanced(2))
+    );
+}
+
+#[test]
+fn directed_parallel_edges_and_loops() {
+    let graph = [vec![1, 1, 0], vec![0, 1, 0]];
+    let circuit = eulerian_circuit(&[0], |&n| graph[n].clone()).unwrap();
+    assert_eq!(circuit.len(), 7);
+    assert_eq!(circuit[0], circuit[6]);
+    check_directed(&graph, &circuit);
+}
+
+#[test]
+fn directed_unbalanced() {
+    // Two nodes with an extra outgoing edge.
+    let graph = [vec![1], vec![], vec![3], vec![]];
+    assert_eq!(
+        eulerian_path(&[0, 1, 2, 3], |&n| graph[n].clone()),
+        Err(EulerianError::Unbalanced(2))
+    );
+    // A node with two extra outgoing edges.
+    let graph = [vec![1, 2], vec![], vec![]];
+    assert_eq!(
+        eulerian_path(&[0], |&n| graph[n].clone()),
+        Err(EulerianError::Unbalanced(0))
+    );
+}
+
+#[test]
+fn directed_disconnected() {
+    let graph = [vec![1], vec![0], vec![3], vec![2]];
+    assert_eq!(
+        eulerian_circuit(&[0, 2], |&n| graph[n].clone()),
+        Err(EulerianError::Disconnected(2))
+    );
+    assert_eq!(
+        eulerian_path(&[0, 2], |&n| graph[n].clone()),
+        Err(EulerianError::Disconnected(2))
+    );
+    // Isolated nodes without edges are not a problem.
+    let graph = [vec![1], vec![0], vec![]];
+    assert_eq!(
+        eulerian_circuit(&[2, 0], |&n| graph[n].clone()),
+        Ok(vec![0, 1, 0])
+    );
+}
+
+#[test]
+fn directed_no_edges() {
+    assert_eq!(eulerian_path(&[1, 2], |_| vec![]), Ok(vec![]));
+    assert_eq!(eulerian_circuit::<u32, _, _>(&[], |_| vec![]), Ok(vec![]));
+}
+
+#[test]
+fn de_bruijn_sequence() {
+    // Every binary word of length 10 appears exactly once in a cyclic de Bruijn sequence.
+    const K: u32 = 10;
+    let mask = (1 << (K - 1)) - 1;
+    let successors = |&n: &u32| [(n << 1) & mask, ((n << 1) | 1) & mask];
+    let circuit = eulerian_circuit(&[0], successors).unwrap();
+    assert_eq!(circuit.len(), (1 << K) + 1);
+    let words = circuit
+        .iter()
+        .tuple_windows()
+        .map(|(&a, &b)| (a << 1) | (b & 1))
+        .sorted()
+        .collect_vec();
+    assert_eq!(words, (0..1 << K).collect_vec());
+}
+
+fn check_undirected<C: Clone + Ord + std::fmt::Debug>(
+    edges: &[(u32, u32, C)],
+    trail: &[(&u32, &u32, C)],
+) {
+    assert!(trail.iter().tuple_windows().all(|(a, b)| a.1 == b.0));
+    let normalize = |(a, b, c): (u32, u32, C)| (a.min(b), a.max(b), c);
+    let used = trail
+        .iter()
+        .map(|(a, b, c)| normalize((**a, **b, c.clone())))
+        .sorted()
+        .collect_vec();
+    let expected = edges.iter().cloned().map(normalize).sorted().collect_vec();
+    assert_eq!(used, expected);
+}
+
+#[test]
+fn undirected_circuit() {
+    let edges = [
+        (1, 2, 'a'),
+        (2, 3, 'b'),
+        (3, 1, 'c'),
+        (3, 4, 'd'),
+        (4, 3, 'e'),
+    ];
+    let circuit = undirected_eulerian_circuit(&edges).unwrap();
+    assert_eq!(circuit.len(), 5);
+    assert_eq!(*circuit[0].0, 1);
+    assert_eq!(circuit[0].0, circuit[4].1);
+    check_undirected(&edges, &circuit);
+}
+
+#[test]
+fn undirected_path() {
+    let edges = [(1, 2, 1), (2, 3, 2), (3, 1, 3), (3, 4, 4)];
+    let path = undirected_eulerian_path(&edges).unwrap();
+    assert_eq!(*path[0].0, 3);
+    assert_eq!(*path[3].1, 4);
+    check_undirected(&edges, &path);
+    assert_eq!(
+        undirected_eulerian_circuit(&edges),
+        Err(EulerianError::Unbalanced(&3))
+    );
+}
+
+#[test]
+fn undirected_loops_and_parallel_edges() {
+    let edges = [(1, 1, 1), (1, 2, 2), (2, 2, 3), (2, 1, 4), (1, 2, 5)];
+    let path = undirected_eulerian_path(&edges).unwrap();
+    check_undirected(&edges, &path);
+    assert_eq!(
+        undirected_eulerian_circuit(&edges),
+        Err(EulerianError::Unbalanced(&1))
+    );
+}
+
+#[test]
+fn undirected_errors() {
+    // A star with four branches has four odd-degree leaves.
+    let edges = [(0, 1, ()), (0, 2, ()), (0, 3, ()), (0, 4, ())];
+    assert_eq!(
+        undirected_eulerian_path(&edges),
+        Err(EulerianError::Unbalanced(&3))
+    );
+    let edges = [(0, 1, ()), (1, 0, ()), (2, 3, ()), (3, 2, ())];
+    assert_eq!(
+        undirected_eulerian_circuit(&edges),
+        Err(EulerianError::Disconnected(&2))
+    );
+    assert_eq!(undirected_eulerian_path::<u32, ()>(&[]), Ok(vec![]));
+}
+
+#[test]
+fn random_undirected() {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    for _ in 0..100 {
+        // A random closed walk, possibly followed by an open one.
+        let len = rng.random_range(1..50);
+        let mut node = 0;
+        let mut edges = Vec::new();
+        for i in 0..len {
+            let next = rng.random_range(0..10);
+            edges.push((node, next, i));
+            node = next;
+        }
+        let closed = rng.random_ratio(1, 2);
+        if closed {
+            edges.push((node, 0, len));
+        }
+        let path = undirected_eulerian_path(&edges).unwrap();
+        check_undirected(&edges, &path);
+        if closed {
+            let circuit = undirected_eulerian_circuit(&edges).unwrap();
+            check_undirected(&edges, &circuit);
+            assert_eq!(circuit[0].0, circuit[circuit.len() - 1].1);
+        }
+    }
+}
+
+#[test]
+fn random_directed() {
+    let mut rng = XorShiftRng::from_seed([4; 16]);
+    for _ in 0..100 {
+        let mut graph = vec![Vec::new(); 10];
+        let mut node = rng.random_range(0..10);
+        for _ in 0..rng.random_range(1..50) {
+            let next = rng.random_range(0..10);
+            graph[node].push(next);
+            node = next;
+        }
+        let nodes = (0..10).collect_vec();
+        let path = eulerian_path(&nodes, |&n| graph[n].clone()).unwrap();
+        check_directed(&graph, &path);
+        let last = *path.last().unwrap();
+        assert!(last == node || last == path[0]);
+    }
+}
@@ -0,0 +1,100 @@
+use pathfinding::prelude::{brent, functional_graph};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn identity() {
+    let graph = functional_graph(&[1, 2, 3], |&n| n);
+    assert_eq!(graph.cycles, vec![vec![1], vec![2], vec![3]]);
+    for (n, info) in &graph.elements {
+        assert_eq!(info.tail_length, 0);
+        assert_eq!(info.cycle_entry, *n);
+        assert_eq!(graph.cycles[info.cycle], vec![*n]);
+        assert!(info.children.is_empty());
+    }
+}
+
+#[test]
+fn permutation() {
+    let graph = functional_graph(&[0, 1, 2, 3, 4], |&n| [2, 3, 0, 4, 1][n]);
+    assert_eq!(graph.cycles, vec![vec![0, 2], vec![1, 3, 4]]);
+    assert!(graph.elements.values().all(|info| info.tail_length == 0));
+}
+
+#[test]
+fn discovered_elements() {
+    // 0 -> 1 -> 2 -> ... -> 9 -> 5
+    let graph = functional_graph(&[0], |&n| if n == 9 { 5 } else { n + 1 });
+    assert_eq!(graph.elements.len(), 10);
+    assert_eq!(graph.cycles, vec![vec![5, 6, 7, 8, 9]]);
+    for n in 0..10 {
+        let info = &graph.elements[&n];
+        assert_eq!(info.tail_length, 5_usize.saturating_sub(n));
+        assert_eq!(info.cycle_entry, n.max(5));
+        assert_eq!(info.cycle, 0);
+    }
+    assert_eq!(graph.elements[&5].children, vec![4]);
+    assert!(graph.elements[&6].children.is_empty());
+    assert!(graph.elements[&0].children.is_empty());
+}
+
+#[test]
+fn successor_called_once() {
+    let mut calls = vec![0; 100];
+    let graph = functional_graph(&(0..100).collect::<Vec<_>>(), |&n| {
+        calls[n] += 1;
+        n * n % 100
+    });
+    assert!(calls.iter().all(|&c| c == 1));
+    assert_eq!(graph.elements.len(), 100);
+}
+
+#[test]
+fn long_tail() {
+    const LEN: usize = 500_000;
+    let graph = functional_graph(&[0], |&n| (n + 1).min(LEN));
+    assert_eq!(graph.cycles, vec![vec![LEN]]);
+    assert_eq!(graph.elements[&0].tail_length, LEN);
+}
+
+#[test]
+fn random_functions() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    for _ in 0..50 {
+        let size = rng.random_range(1..200);
+        let images = (0..size)
+            .map(|_| rng.random_range(0..size))
+            .collect::<Vec<_>>();
+        let domain = (0..size).collect::<Vec<_>>();
+        let graph = functional_graph(&domain, |&n| images[n]);
+        assert_eq!(graph.elements.len(), size);
+        // Cycles are disjoint, closed, and cover exactly the elements with no tail.
+        let cyclic = graph.cycles.iter().map(Vec::len).sum::<usize>();
+        let no_tail = graph.elements.values().filter(|i| i.tail_length == 0);
+        assert_eq!(cyclic, no_tail.count());
+        for (c, cycle) in graph.cycles.iter().enumerate() {
+            for (i, &n) in cycle.iter().enumerate() {
+                assert_eq!(images[n], cycle[(i + 1) % cycle.len()]);
+                assert_eq!(graph.elements[&n].cycle, c);
+            }
+        }
+        let mut children_count = 0;
+        for (&n, info) in &graph.elements {
+            // Compare with a single-start cycle detection.
+            let (lam, _, mu) = brent(n, |x| images[x]);
+            assert_eq!(info.tail_length, mu);
+            assert_eq!(graph.cycles[info.cycle].len(), lam);
+            let mut entry = n;
+            for _ in 0..mu {
+                entry = images[entry];
+            }
+            assert_eq!(info.cycle_entry, entry);
+            for &child in &info.children {
+                assert_eq!(images[child], n);
+                assert!(graph.elements[&child].tail_length > 0);
+            }
+            children_count += info.children.len();
+        }
+        assert_eq!(children_count, size - cyclic);
+    }
+}
@@ -0,0 +1,126 @@
+use itertools::Itertools;
+use pathfinding::prelude::{IncrementalTopo, bfs_reach};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+fn check_order(graph: &IncrementalTopo<usize>) {
+    let order = graph.order().copied().collect_vec();
+    assert_eq!(order.len(), graph.len());
+    for (i, n) in order.iter().enumerate() {
+        assert_eq!(graph.position(n), Some(i));
+        for s in graph.successors(n) {
+            assert!(
+                graph.position(s).unwrap() > i,
+                "{n} -> {s} is not in topological order"
+            );
+        }
+    }
+}
+
+#[test]
+fn empty() {
+    let graph = IncrementalTopo::<usize>::new();
+    assert!(graph.is_empty());
+    assert_eq!(graph.len(), 0);
+    assert_eq!(graph.order().next(), None);
+    assert_eq!(graph.position(&0), None);
+}
+
+#[test]
+fn nodes_keep_insertion_order() {
+    let mut graph = IncrementalTopo::new();
+    assert!(graph.add_node(3));
+    assert!(graph.add_node(1));
+    assert!(!graph.add_node(3));
+    assert!(graph.add_node(2));
+    assert_eq!(graph.order().copied().collect_vec(), vec![3, 1, 2]);
+    assert!(graph.contains_node(&1));
+    assert!(!graph.contains_node(&4));
+}
+
+#[test]
+fn reorder_on_insertion() {
+    let mut graph = IncrementalTopo::new();
+    for n in 0..6 {
+        graph.add_node(n);
+    }
+    assert_eq!(graph.add_edge(4, 1), Ok(true));
+    assert_eq!(graph.add_edge(5, 4), Ok(true));
+    assert_eq!(graph.add_edge(5, 4), Ok(false));
+    check_order(&graph);
+    // Nodes which do not need to move keep their relative positions.
+    assert_eq!(graph.order().copied().collect_vec(), vec![0, 5, 2, 3, 4, 1]);
+}
+
+#[test]
+fn reject_cycles() {
+    let mut graph = IncrementalTopo::new();
+    assert_eq!(graph.add_edge(1, 1), Err(vec![1]));
+    assert_eq!(graph.add_edge(0, 1), Ok(true));
+    assert_eq!(graph.add_edge(1, 2), Ok(true));
+    assert_eq!(graph.add_edge(2, 3), Ok(true));
+    assert_eq!(graph.add_edge(3, 1), Err(vec![3, 1, 2]));
+    assert!(!graph.contains_edge(&3, &1));
+    assert_eq!(graph.add_edge(3, 0), Err(vec![3, 0, 1, 2]));
+    assert_eq!(graph.order().copied().collect_vec(), vec![0, 1, 2, 3]);
+    // Once an edge is removed, the reverse edge can be inserted.
+    assert!(graph.remove_edge(&1, &2));
+    assert!(!graph.remove_edge(&1, &2));
+    assert_eq!(graph.add_edge(3, 1), Ok(true));
+    check_order(&graph);
+}
+
+#[test]
+fn successors_keep_insertion_order_after_removal() {
+    let mut graph = IncrementalTopo::new();
+    for to in 1..5 {
+        assert_eq!(graph.add_edge(0, to), Ok(true));
+    }
+    assert!(graph.remove_edge(&0, &1));
+    assert_eq!(graph.successors(&0).copied().collect_vec(), vec![2, 3, 4]);
+    assert!(graph.remove_edge(&0, &3));
+    assert_eq!(graph.add_edge(0, 1), Ok(true));
+    assert_eq!(graph.successors(&0).copied().collect_vec(), vec![2, 4, 1]);
+}
+
+#[test]
+fn rejected_edge_adds_nodes() {
+    let mut graph = IncrementalTopo::new();
+    assert_eq!(graph.add_edge('a', 'a'), Err(vec!['a']));
+    assert!(graph.contains_node(&'a'));
+    assert_eq!(graph.successors(&'a').count(), 0);
+    assert_eq!(graph.successors(&'b').count(), 0);
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    for _ in 0..20 {
+        let size = 30;
+        let mut graph = IncrementalTopo::new();
+        for _ in 0..200 {
+            let (from, to) = (rng.random_range(0..size), rng.random_range(0..size));
+            let existing = graph.contains_edge(&from, &to);
+            let creates_cycle =
+                bfs_reach(to, |&n| graph.successors(&n).copied().collect_vec()).any(|n| n == from);
+            match graph.add_edge(from, to) {
+                Ok(added) => {
+                    assert!(!creates_cycle);
+                    assert_eq!(added, !existing);
+                }
+                Err(cycle) => {
+                    assert!(creates_cycle);
+                    assert_eq!(cycle[0], from);
+                    assert!(cycle.iter().all_unique());
+                    for (a, b) in cycle.iter().circular_tuple_windows() {
+                        assert!(
+                            (*a, *b) == (from, to) || graph.contains_edge(a, b),
+                            "{a} -> {b} is not an edge"
+                        );
+                    }
+                }
+            }
+            check_order(&graph);
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use itertools::Itertools;
+use pathfinding::directed::bfs::bfs_reach;
 use pathfinding::directed::strongly_connected_components::*;
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
 use std::{collections::hash_map::HashMap, sync::LazyLock};
 
 // Tests in this file use the example at
@@ -119,3 +123,110 @@ fn loops() {
     c.sort();
     assert_eq!(c, vec![vec![0], vec![42]]);
 }
+
+#[test]
+fn condensation_dag() {
+    let c = condensation(&(0..15).collect::<Vec<_>>(), successors);
+    assert_eq!(c.components.len(), EXPECTED.len());
+    assert_eq!(c.membership.len(), 16);
+    for (i, component) in c.components.iter().enumerate() {
+        let mut sorted = component.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, SCC[&component[0]]);
+        assert!(component.iter().all(|n| c.membership[n] == i));
+    }
+    // Edges between components, identified by their smallest node.
+    let smallest = |i: usize| *c.components[i].iter().min().unwrap();
+    let mut edges = Vec::new();
+    for (i, succs) in c.edges.iter().enumerate() {
+        for &s in succs {
+            assert!(s > i, "components are not in topological order");
+            edges.push((smallest(i), smallest(s)));
+        }
+    }
+    edges.sort_unstable();
+    assert_eq!(
+        edges,
+        vec![(0, 5), (0, 9), (5, 6), (5, 13), (6, 15), (9, 13), (13, 15)]
+    );
+}
+
+#[test]
+fn condensation_requests_successors_once() {
+    let mut requested = 0;
+    let c = condensation(&[3], |&n| {
+        requested += 1;
+        successors(&n)
+    });
+    assert_eq!(requested, 16);
+    assert_eq!(c.membership[&3], 0);
+    assert_eq!(c.components.last(), Some(&vec![15]));
+}
+
+#[test]
+fn condensation_empty() {
+    let c = condensation(&[], successors);
+    assert!(c.components.is_empty());
+    assert!(c.membership.is_empty());
+    assert!(c.edges.is_empty());
+}
+
+#[test]
+fn deep_chain() {
+    // A long chain must not overflow the stack.
+    let c = strongly_connected_components(&[0], |&n: &usize| (n < 500_000).then_some(n + 1));
+    assert_eq!(c.len(), 500_001);
+    assert_eq!(c[0], vec![500_000]);
+    assert_eq!(c[500_000], vec![0]);
+}
+
+#[test]
+fn deep_cycle() {
+    let mut c = strongly_connected_component(&0, |&n: &usize| [(n + 1) % 500_000]);
+    c.sort_unstable();
+    assert_eq!(c, (0..500_000).collect_vec());
+}
+
+#[test]
+fn lazy_iterator() {
+    // 0 -> 1 -> 2 -> ... with every node being its own component: only the
+    // explored part of the graph is visited when the first component is yielded.
+    let mut requested = 0;
+    let mut components = strongly_connected_components_iter(&[0], |&n: &usize| {
+        requested += 1;
+        (n < 10).then_some(n + 1)
+    });
+    assert_eq!(components.next(), Some(vec![10]));
+    assert_eq!(components.next(), Some(vec![9]));
+    assert_eq!(components.by_ref().count(), 9);
+    assert_eq!(components.next(), None);
+    assert_eq!(requested, 11);
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    for _ in 0..20 {
+        let size = 40;
+        let graph = (0..size)
+            .map(|_| (0..size).filter(|_| rng.random_ratio(1, 25)).collect_vec())
+            .collect_vec();
+        let reach = (0..size)
+            .map(|n| bfs_reach(n, |&n| graph[n].clone()).collect_vec())
+            .collect_vec();
+        let components =
+            strongly_connected_components(&(0..size).collect_vec(), |&n| graph[n].clone());
+        assert_eq!(components.iter().map(Vec::len).sum::<usize>(), size);
+        let position = |n: usize| components.iter().position(|c| c.contains(&n)).unwrap();
+        for a in 0..size {
+            for b in 0..size {
+                let (ab, ba) = (reach[a].contains(&b), reach[b].contains(&a));
+                assert_eq!(position(a) == position(b), ab && ba);
+                // Components are in reverse topological order.
+                if ab {
+                    assert!(position(a) >= position(b));
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use itertools::Itertools;
 use pathfinding::directed::topological_sort::topological_sort as tsort;
-use pathfinding::directed::topological_sort::topological_sort_into_groups;
+use pathfinding::directed::topological_sort::{
+    topological_sort_by_key, topological_sort_into_groups, topological_sort_into_groups_or_cycle,
+    topological_sort_lexicographic, topological_sort_or_cycle, topological_sorts,
+};
 use rand::rngs;
 use rand::seq::SliceRandom;
+use std::cmp::Reverse;
 
 #[test]
 fn empty() {
@@ -114,3 +118,215 @@ fn tsig_self_edge() {
         Err((vec![vec![0], vec![1, 2]], vec![3]))
     );
 }
+
+#[test]
+fn lexicographic_order() {
+    // Divisors are always smaller than the numbers they divide, so
+    // the smallest order is the natural one.
+    let mut rng = rngs::ThreadRng::default();
+    let mut ints = (1..1000).collect_vec();
+    ints.shuffle(&mut rng);
+    let sorted = topological_sort_lexicographic(&ints, |&n| {
+        (2..).map(|m| m * n).take_while(|&p| p < 1000).collect_vec()
+    });
+    assert_eq!(sorted, Ok((1..1000).collect_vec()));
+}
+
+#[test]
+fn lexicographic_smallest() {
+    // 5 -> 1, 4 -> 2, 3 is independent
+    let succs: &[&[usize]] = &[&[], &[], &[], &[], &[2], &[1]];
+    let sorted = topological_sort_lexicographic(&[1, 2, 3, 4, 5], |&n| succs[n].iter().copied());
+    assert_eq!(sorted, Ok(vec![3, 4, 2, 5, 1]));
+    let sorted = topological_sort_by_key(
+        &[1, 2, 3, 4, 5],
+        |&n| succs[n].iter().copied(),
+        |&n| Reverse(n),
+    );
+    assert_eq!(sorted, Ok(vec![5, 4, 3, 2, 1]));
+}
+
+#[test]
+fn by_key_ties_follow_input_order() {
+    let nodes = [3, 1, 4, 5, 9, 2, 6];
+    let sorted = topological_sort_by_key(&nodes, |_| vec![], |_| 0);
+    assert_eq!(sorted, Ok(nodes.to_vec()));
+    let sorted = topological_sort_by_key(&nodes, |_| vec![], |&n| n % 2);
+    assert_eq!(sorted, Ok(vec![4, 2, 6, 3, 1, 5, 9]));
+}
+
+#[test]
+fn by_key_discovers_nodes() {
+    let sorted =
+        topological_sort_lexicographic(&[10], |&n| if n > 0 { vec![n - 1] } else { vec![] });
+    assert_eq!(sorted, Ok((0..=10).rev().collect_vec()));
+}
+
+#[test]
+fn by_key_complexity() {
+    let mut requested = 0;
+    let result = topological_sort_lexicographic(&[1], |&n| {
+        requested += 1;
+        if n < 999 { vec![n + 1] } else { vec![] }
+    });
+    assert_eq!(result, Ok((1..1000).collect_vec()));
+    assert_eq!(requested, 999);
+}
+
+#[test]
+fn by_key_cycle() {
+    // 0 -> 1 -> 2 -> 3 -> 2, 3 -> 4, 5 isolated
+    let succs: &[&[usize]] = &[&[1], &[2], &[3], &[2, 4], &[], &[]];
+    let result =
+        topological_sort_lexicographic(&(0..6).collect_vec(), |&n| succs[n].iter().copied());
+    assert_eq!(result, Err((vec![0, 1, 5], vec![2, 3, 4])));
+}
+
+#[test]
+fn all_orders_without_edges() {
+    let orders = topological_sorts(&[1, 2, 3], |_| vec![]).collect_vec();
+    assert_eq!(orders, (1..=3).permutations(3).collect_vec());
+}
+
+#[test]
+fn all_orders_are_valid_and_complete() {
+    // Compare with the filtered permutations for a small graph.
+    let succs: &[&[usize]] = &[&[2], &[2, 3], &[4], &[], &[], &[3]];
+    let nodes = (0..6).collect_vec();
+    let orders = topological_sorts(&nodes, |&n| succs[n].iter().copied()).collect_vec();
+    let expected = nodes
+        .iter()
+        .copied()
+        .permutations(nodes.len())
+        .filter(|p| {
+            p.iter().enumerate().all(|(i, &a)| {
+                succs[a]
+                    .iter()
+                    .all(|b| p.iter().position(|x| x == b).unwrap() > i)
+            })
+        })
+        .collect_vec();
+    assert_eq!(orders, expected);
+}
+
+#[test]
+fn all_orders_discovers_nodes() {
+    let orders =
+        topological_sorts(&[2], |&n| if n > 0 { vec![n - 1] } else { vec![] }).collect_vec();
+    assert_eq!(orders, vec![vec![2, 1, 0]]);
+}
+
+#[test]
+fn all_orders_with_cycle() {
+    let mut orders = topological_sorts(&[0, 1, 2, 3], |&n| match n {
+        0 | 2 => vec![1],
+        1 => vec![2],
+        _ => vec![],
+    });
+    assert_eq!(orders.next(), None);
+    assert_eq!(orders.next(), None);
+}
+
+#[test]
+fn all_orders_empty() {
+    let orders = topological_sorts(&[], |_: &usize| vec![]).collect_vec();
+    assert_eq!(orders, vec![Vec::<usize>::new()]);
+}
+
+#[test]
+fn all_orders_lazy() {
+    // 20! orders, only the first ones are computed.
+    let nodes = (0..20).collect_vec();
+    let mut orders = topological_sorts(&nodes, |_| vec![]);
+    assert_eq!(orders.next().as_ref(), Some(&nodes));
+    let mut second = nodes;
+    second.swap(18, 19);
+    assert_eq!(orders.next(), Some(second));
+    assert_eq!(orders.take(1000).count(), 1000);
+}
+
+// Check that `cycle` is a non-empty cycle of the graph with no repeated node.
+fn assert_is_cycle(cycle: &[usize], succs: &[&[usize]]) {
+    assert!(!cycle.is_empty());
+    assert!(cycle.iter().all_unique(), "{cycle:?} has repeated nodes");
+    for (a, b) in cycle.iter().circular_tuple_windows() {
+        assert!(succs[*a].contains(b), "{a} -> {b} is not an edge");
+    }
+}
+
+#[test]
+fn or_cycle_sorted() {
+    let succs: &[&[usize]] = &[&[1, 2], &[3], &[3], &[]];
+    let sorted = topological_sort_or_cycle(&[0], |&n| succs[n].iter().copied());
+    assert_eq!(sorted, Ok(vec![0, 2, 1, 3]));
+}
+
+#[test]
+fn or_cycle_self_loop() {
+    let succs: &[&[usize]] = &[&[1], &[1]];
+    assert_eq!(
+        topological_sort_or_cycle(&[0], |&n| succs[n].iter().copied()),
+        Err(vec![1])
+    );
+}
+
+#[test]
+fn or_cycle_reports_cycle() {
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 2, with an extra chord 3 -> 5 -> 6
+    let succs: &[&[usize]] = &[&[1], &[2], &[3], &[5, 4], &[2], &[6], &[]];
+    for root in 0..succs.len() {
+        let result = topological_sort_or_cycle(&[root], |&n| succs[n].iter().copied());
+        if root >= 5 {
+            assert!(result.is_ok());
+        } else {
+            let cycle = result.unwrap_err();
+            assert_is_cycle(&cycle, succs);
+            assert_eq!(cycle.iter().copied().sorted().collect_vec(), vec![2, 3, 4]);
+        }
+    }
+    assert_eq!(tsort(&[0], |&n| succs[n].iter().copied()).unwrap_err(), 2);
+}
+
+#[test]
+fn or_cycle_deep_graph() {
+    // A long chain closed by a back edge must not overflow the stack.
+    let result = topological_sort_or_cycle(&[0], |&n: &usize| [(n + 1) % 1_000_000]);
+    let cycle = result.unwrap_err();
+    assert_eq!(cycle, (0..1_000_000).collect_vec());
+    let result = tsort(&[0], |&n: &usize| (n < 1_000_000).then_some(n + 1));
+    assert_eq!(result, Ok((0..=1_000_000).collect_vec()));
+}
+
+#[test]
+fn into_groups_or_cycle() {
+    let succs: &[&[usize]] = &[&[1], &[2], &[3], &[2, 4], &[]];
+    let nodes = (0..succs.len()).collect_vec();
+    let (groups, cycle) =
+        topological_sort_into_groups_or_cycle(&nodes, |&n| succs[n].iter().copied()).unwrap_err();
+    assert_eq!(groups, vec![vec![0], vec![1]]);
+    assert_is_cycle(&cycle, succs);
+    assert_eq!(cycle.iter().copied().sorted().collect_vec(), vec![2, 3]);
+    let succs: &[&[usize]] = &[&[1, 2], &[3], &[3], &[]];
+    let nodes = (0..succs.len()).collect_vec();
+    assert_eq!(
+        topological_sort_into_groups_or_cycle(&nodes, |&n| succs[n].iter().copied()).map(
+            |groups| groups
+                .into_iter()
+                .map(|g| g.into_iter().sorted().collect_vec())
+                .collect_vec()
+        ),
+        Ok(vec![vec![0], vec![1, 2], vec![3]])
+    );
+}
+
+#[test]
+fn into_groups_or_cycle_reaches_cycle_through_dead_nodes() {
+    // 1 and 2 form a cycle, 0 is blocked behind it, and 3 is blocked behind 0.
+    let succs: &[&[usize]] = &[&[3], &[2, 0], &[1], &[]];
+    let nodes = (0..succs.len()).collect_vec();
+    let (groups, cycle) =
+        topological_sort_into_groups_or_cycle(&nodes, |&n| succs[n].iter().copied()).unwrap_err();
+    assert!(groups.is_empty());
+    assert_is_cycle(&cycle, succs);
+    assert_eq!(cycle.iter().copied().sorted().collect_vec(), vec![1, 2]);
+}
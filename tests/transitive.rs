@@ -0,0 +1,112 @@
+use itertools::Itertools;
+use pathfinding::prelude::{bfs_reach, transitive_closure, transitive_reduction};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+// Random graph on `size` nodes. If `dag` is true, edges only go from
+// smaller to larger nodes.
+fn random_graph(rng: &mut XorShiftRng, size: usize, dag: bool) -> Vec<Vec<usize>> {
+    (0..size)
+        .map(|a| {
+            (0..size)
+                .filter(|&b| (!dag || a < b) && rng.random_ratio(1, 8))
+                .collect()
+        })
+        .collect()
+}
+
+fn reachable(graph: &[Vec<usize>], from: usize) -> Vec<usize> {
+    bfs_reach(from, |&n| graph[n].clone()).sorted().collect()
+}
+
+#[test]
+fn closure_empty() {
+    let closure = transitive_closure(&[], |_: &usize| vec![]);
+    assert!(closure.is_empty());
+    assert!(!closure.reaches(&0, &0));
+    assert_eq!(closure.reachable(&0).count(), 0);
+}
+
+#[test]
+fn closure_unknown_nodes() {
+    let closure = transitive_closure(&[0], |&n| if n < 3 { vec![n + 1] } else { vec![] });
+    assert_eq!(closure.len(), 4);
+    assert!(closure.reaches(&0, &3));
+    assert!(!closure.reaches(&3, &0));
+    assert!(!closure.reaches(&0, &4));
+    assert!(!closure.reaches(&4, &4));
+}
+
+#[test]
+fn closure_random_graphs() {
+    let mut rng = XorShiftRng::from_seed([4; 16]);
+    for size in [1, 10, 63, 64, 65, 150] {
+        let graph = random_graph(&mut rng, size, false);
+        let nodes = (0..size).collect_vec();
+        let mut requested = 0;
+        let closure = transitive_closure(&nodes, |&n| {
+            requested += 1;
+            graph[n].clone()
+        });
+        assert_eq!(requested, size);
+        assert_eq!(closure.len(), size);
+        for a in 0..size {
+            let expected = reachable(&graph, a);
+            assert_eq!(
+                closure.reachable(&a).copied().sorted().collect_vec(),
+                expected
+            );
+            for b in 0..size {
+                assert_eq!(closure.reaches(&a, &b), expected.contains(&b));
+            }
+        }
+    }
+}
+
+#[test]
+fn reduction_diamond() {
+    let succs: &[&[usize]] = &[&[1, 2, 3, 3], &[3], &[3], &[]];
+    let reduction = transitive_reduction(&[0], |&n| succs[n].iter().copied()).unwrap();
+    assert_eq!(
+        reduction,
+        HashMap::from([(0, vec![1, 2]), (1, vec![3]), (2, vec![3]), (3, vec![])])
+    );
+}
+
+#[test]
+fn reduction_cycles() {
+    assert_eq!(transitive_reduction(&[0], |&n| vec![n]), Err(0));
+    let succs: &[&[usize]] = &[&[1], &[2], &[3], &[1]];
+    let node = transitive_reduction(&[0], |&n| succs[n].iter().copied()).unwrap_err();
+    assert!([1, 2, 3].contains(&node));
+}
+
+#[test]
+fn reduction_random_dags() {
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    for size in [1, 10, 70, 120] {
+        let graph = random_graph(&mut rng, size, true);
+        let nodes = (0..size).collect_vec();
+        let reduction = transitive_reduction(&nodes, |&n| graph[n].clone()).unwrap();
+        assert_eq!(reduction.len(), size);
+        let reduced = (0..size).map(|n| reduction[&n].clone()).collect_vec();
+        for a in 0..size {
+            // Reachability is preserved, and kept edges are ordered as in the
+            // original graph.
+            assert_eq!(reachable(&reduced, a), reachable(&graph, a));
+            assert!(reduced[a].iter().all(|s| graph[a].contains(s)));
+            assert!(reduced[a].is_sorted());
+            // No edge can be removed.
+            for &b in &reduced[a] {
+                assert!(
+                    reduced[a]
+                        .iter()
+                        .filter(|&&c| c != b)
+                        .all(|&c| !reachable(&graph, c).contains(&b)),
+                    "{a} -> {b} is redundant"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use itertools::Itertools;
+use pathfinding::prelude::{
+    Matrix, christofides_tour, held_karp_cycle, held_karp_path, nearest_neighbour_tour, or_opt,
+    two_opt,
+};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+fn random_costs(rng: &mut XorShiftRng, size: usize) -> Matrix<u32> {
+    let mut costs = Matrix::new(size, size, 0);
+    for cost in costs.values_mut() {
+        *cost = rng.random_range(1..100);
+    }
+    costs
+}
+
+fn path_cost(costs: &Matrix<u32>, path: &[usize]) -> u32 {
+    path.iter()
+        .tuple_windows()
+        .map(|(&a, &b)| costs[(a, b)])
+        .sum()
+}
+
+fn tour_cost(costs: &Matrix<u32>, tour: &[usize]) -> u32 {
+    path_cost(costs, tour) + costs[(tour[tour.len() - 1], tour[0])]
+}
+
+fn is_permutation(path: &[usize], size: usize) -> bool {
+    path.iter().copied().sorted().eq(0..size)
+}
+
+#[test]
+fn empty_and_small() {
+    let costs = Matrix::<u32>::new(0, 0, 0);
+    assert_eq!(held_karp_path(&costs, None), (vec![], 0));
+    let costs = Matrix::new(1, 1, 7u32);
+    assert_eq!(held_karp_cycle(&costs, 0), (vec![0], 0));
+    assert_eq!(held_karp_path(&costs, None), (vec![0], 0));
+    assert_eq!(held_karp_path(&costs, Some(0)), (vec![0], 0));
+    let costs = Matrix::from_rows(vec![vec![0, 3], vec![5, 0]]).unwrap();
+    assert_eq!(held_karp_cycle(&costs, 1), (vec![1, 0], 8));
+    assert_eq!(held_karp_path(&costs, None), (vec![0, 1], 3));
+    assert_eq!(held_karp_path(&costs, Some(1)), (vec![1, 0], 5));
+}
+
+#[test]
+fn asymmetric_costs() {
+    // Going around clockwise is cheap, counter-clockwise is expensive.
+    let size = 6;
+    let mut costs = Matrix::new(size, size, 100u32);
+    for i in 0..size {
+        costs[(i, (i + 1) % size)] = 1;
+    }
+    assert_eq!(held_karp_cycle(&costs, 2), (vec![2, 3, 4, 5, 0, 1], 6));
+    assert_eq!(held_karp_path(&costs, Some(4)), (vec![4, 5, 0, 1, 2, 3], 5));
+}
+
+#[test]
+#[should_panic(expected = "square")]
+fn non_square() {
+    let _ = held_karp_cycle(&Matrix::new(2, 3, 0u32), 0);
+}
+
+#[test]
+#[should_panic(expected = "valid city")]
+fn invalid_start() {
+    let _ = held_karp_path(&Matrix::new(3, 3, 0u32), Some(3));
+}
+
+#[test]
+fn random_matrices() {
+    let mut rng = XorShiftRng::from_seed([8; 16]);
+    for _ in 0..50 {
+        let size = rng.random_range(2..8);
+        let costs = random_costs(&mut rng, size);
+        let start = rng.random_range(0..size);
+        let (tour, cost) = held_karp_cycle(&costs, start);
+        assert!(is_permutation(&tour, size));
+        assert_eq!(tour[0], start);
+        assert_eq!(tour_cost(&costs, &tour), cost);
+        let best_tour = (0..size)
+            .permutations(size)
+            .map(|p| tour_cost(&costs, &p))
+            .min()
+            .unwrap();
+        assert_eq!(cost, best_tour);
+        let (path, cost) = held_karp_path(&costs, None);
+        assert!(is_permutation(&path, size));
+        assert_eq!(path_cost(&costs, &path), cost);
+        let best_path = (0..size)
+            .permutations(size)
+            .map(|p| path_cost(&costs, &p))
+            .min()
+            .unwrap();
+        assert_eq!(cost, best_path);
+        let (path, cost) = held_karp_path(&costs, Some(start));
+        assert!(is_permutation(&path, size));
+        assert_eq!(path[0], start);
+        assert_eq!(path_cost(&costs, &path), cost);
+        let best_path = (0..size)
+            .permutations(size)
+            .filter(|p| p[0] == start)
+            .map(|p| path_cost(&costs, &p))
+            .min()
+            .unwrap();
+        assert_eq!(cost, best_path);
+    }
+}
+
+#[test]
+fn puzzle_size() {
+    // Cities on a line: the best open path goes from one end to the other.
+    let size = 16;
+    let positions = (0..size).map(|i| (i * 7) % size).collect_vec();
+    let costs = Matrix::from_fn(size, size, |(i, j)| positions[i].abs_diff(positions[j]));
+    let (path, cost) = held_karp_path(&costs, None);
+    assert_eq!(cost, size - 1);
+    assert!(is_permutation(&path, size));
+    let (_, cost) = held_karp_cycle(&costs, 5);
+    assert_eq!(cost, 2 * (size - 1));
+}
+
+fn random_points(rng: &mut XorShiftRng, size: usize) -> Matrix<i32> {
+    let points = (0..size)
+        .map(|_| (rng.random_range(0..100), rng.random_range(0..100)))
+        .collect_vec();
+    Matrix::from_fn(size, size, |(i, j)| {
+        let (a, b): ((i32, i32), (i32, i32)) = (points[i], points[j]);
+        (a.0 - b.0).abs() + (a.1 - b.1).abs()
+    })
+}
+
+#[test]
+fn heuristics_small() {
+    let costs = Matrix::new(1, 1, 7);
+    assert_eq!(nearest_neighbour_tour(&costs, 0), (vec![0], 0));
+    assert_eq!(christofides_tour(&costs, 0), (vec![0], 0));
+    let mut tour = vec![0];
+    assert_eq!(two_opt(&costs, &mut tour), 0);
+    assert_eq!(or_opt(&costs, &mut tour), 0);
+    let costs = Matrix::from_rows(vec![vec![0, 3], vec![3, 0]]).unwrap();
+    assert_eq!(christofides_tour(&costs, 1), (vec![1, 0], 6));
+    let mut tour = vec![1, 0];
+    assert_eq!(two_opt(&costs, &mut tour), 6);
+    assert_eq!(or_opt(&costs, &mut tour), 6);
+    assert_eq!(tour, vec![1, 0]);
+}
+
+#[test]
+fn or_opt_asymmetric() {
+    // Going around clockwise is cheap, counter-clockwise is expensive.
+    let size = 6;
+    let mut costs = Matrix::new(size, size, 100u32);
+    for i in 0..size {
+        costs[(i, (i + 1) % size)] = 1;
+    }
+    let mut tour = vec![2, 4, 3, 5, 0, 1];
+    assert_eq!(or_opt(&costs, &mut tour), 6);
+    assert_eq!(tour, vec![2, 3, 4, 5, 0, 1]);
+}
+
+#[test]
+fn random_heuristics() {
+    let mut rng = XorShiftRng::from_seed([9; 16]);
+    for _ in 0..50 {
+        let size = rng.random_range(2..9);
+        let costs = random_points(&mut rng, size);
+        let start = rng.random_range(0..size);
+        let (_, optimal) = held_karp_cycle(&costs, start);
+        let (mut tour, cost) = nearest_neighbour_tour(&costs, start);
+        assert!(is_permutation(&tour, size));
+        assert_eq!(tour[0], start);
+        assert!(cost >= optimal);
+        let improved = two_opt(&costs, &mut tour);
+        assert!(is_permutation(&tour, size));
+        assert_eq!(tour[0], start);
+        assert!(improved <= cost && improved >= optimal);
+        let (mut tour, cost) = christofides_tour(&costs, start);
+        assert!(is_permutation(&tour, size));
+        assert_eq!(tour[0], start);
+        assert_eq!(pathfinding::tsp::tour_cost(&costs, &tour), cost);
+        assert!(cost >= optimal && 2 * cost <= 3 * optimal);
+        let improved = or_opt(&costs, &mut tour);
+        assert!(is_permutation(&tour, size));
+        assert_eq!(tour[0], start);
+        assert!(improved <= cost && improved >= optimal);
+    }
+}
+
+#[test]
+fn larger_instance() {
+    let mut rng = XorShiftRng::from_seed([10; 16]);
+    let size = 200;
+    let costs = random_points(&mut rng, size);
+    let (mut tour, cost) = christofides_tour(&costs, 0);
+    assert!(is_permutation(&tour, size));
+    let improved = two_opt(&costs, &mut tour);
+    let improved = or_opt(&costs, &mut tour).min(improved);
+    assert!(is_permutation(&tour, size));
+    assert!(improved <= cost);
+}
+
+#[test]
+fn christofides_upper_triangle() {
+    // The lower triangle is never read.
+    let mut rng = XorShiftRng::from_seed([14; 16]);
+    let symmetric = random_points(&mut rng, 7);
+    let mut costs = symmetric.clone();
+    for i in 0..7 {
+        for j in 0..i {
+            costs[(i, j)] = 1000;
+        }
+    }
+    let (tour, cost) = christofides_tour(&costs, 3);
+    assert_eq!(christofides_tour(&symmetric, 3), (tour.clone(), cost));
+    assert_eq!(pathfinding::tsp::tour_cost(&symmetric, &tour), cost);
+}
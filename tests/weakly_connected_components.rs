@@ -0,0 +1,73 @@
+use itertools::Itertools;
+use pathfinding::prelude::{connected_components, weakly_connected_components};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::collections::HashSet;
+
+#[test]
+fn empty() {
+    let components = weakly_connected_components(&[], |_: &usize| vec![]);
+    assert!(components.is_empty());
+}
+
+#[test]
+fn isolated_nodes() {
+    let components = weakly_connected_components(&[3, 1, 3, 2], |_| vec![]);
+    assert_eq!(
+        components,
+        vec![HashSet::from([3]), HashSet::from([1]), HashSet::from([2])]
+    );
+}
+
+#[test]
+fn discovered_nodes() {
+    // 0 and 10 are only linked through nodes absent from `nodes`.
+    let mut requested = 0;
+    let components = weakly_connected_components(&[0, 10], |&n| {
+        requested += 1;
+        match n {
+            0 | 10 => vec![5],
+            5 => vec![6, 5],
+            _ => vec![],
+        }
+    });
+    assert_eq!(components, vec![HashSet::from([0, 5, 6, 10])]);
+    assert_eq!(requested, 4);
+}
+
+#[test]
+fn deep_chain() {
+    let components =
+        weakly_connected_components(&[0, 1_000_000], |&n: &usize| (n < 500_000).then_some(n + 1));
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[0].len(), 500_001);
+    assert_eq!(components[1], HashSet::from([1_000_000]));
+}
+
+#[test]
+fn random_graphs() {
+    // Compare with the undirected version on a symmetric graph.
+    let mut rng = XorShiftRng::from_seed([8; 16]);
+    for _ in 0..20 {
+        let size = 100;
+        let graph = (0..size)
+            .map(|_| (0..size).filter(|_| rng.random_ratio(1, 150)).collect_vec())
+            .collect_vec();
+        let mut symmetric = graph.clone();
+        for (a, succs) in graph.iter().enumerate() {
+            for &b in succs {
+                symmetric[b].push(a);
+            }
+        }
+        let nodes = (0..size).collect_vec();
+        let weak = weakly_connected_components(&nodes, |&n| graph[n].clone());
+        let expected = connected_components(&nodes, |&n| symmetric[n].clone());
+        let sorted = |c: Vec<HashSet<usize>>| {
+            c.into_iter()
+                .map(|s| s.into_iter().sorted().collect_vec())
+                .sorted()
+                .collect_vec()
+        };
+        assert_eq!(sorted(weak), sorted(expected));
+    }
+}
@@ -0,0 +1,119 @@
+use itertools::Itertools;
+use noisy_float::prelude::*;
+use pathfinding::prelude::{elementary_cycles, find_negative_cycle, minimum_mean_cycle};
+use rand::{RngExt as _, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+type Graph = Vec<HashMap<usize, i64>>;
+
+fn random_graph(rng: &mut XorShiftRng, size: usize, costs: std::ops::Range<i64>) -> Graph {
+    let mut graph = vec![HashMap::new(); size];
+    for succs in &mut graph {
+        for s in 0..size {
+            if rng.random_ratio(1, 4) {
+                succs.insert(s, rng.random_range(costs.clone()));
+            }
+        }
+    }
+    graph
+}
+
+fn cycle_weight(graph: &Graph, cycle: &[usize]) -> i64 {
+    cycle
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| graph[*a][b])
+        .sum()
+}
+
+fn successors(graph: &Graph) -> impl FnMut(&usize) -> Vec<(usize, i64)> + '_ {
+    |&n| graph[n].iter().map(|(&s, &c)| (s, c)).collect()
+}
+
+#[test]
+fn no_cycle() {
+    let successors = |&n: &u32| if n < 10 { vec![(n + 1, -1)] } else { vec![] };
+    assert_eq!(minimum_mean_cycle(&[0], successors), None);
+    assert_eq!(find_negative_cycle(&[0], successors), None);
+    assert_eq!(minimum_mean_cycle(&[], successors), None);
+    assert_eq!(find_negative_cycle(&[], successors), None);
+}
+
+#[test]
+fn self_loop() {
+    let successors = |&n: &u32| match n {
+        0 => vec![(1, 5), (0, -1)],
+        _ => vec![(0, -3)],
+    };
+    assert_eq!(minimum_mean_cycle(&[1], successors), Some((vec![0], -1)));
+    let cycle = find_negative_cycle(&[1], successors).unwrap();
+    assert!(cycle == vec![0] || cycle.len() == 2);
+}
+
+#[test]
+fn fractional_mean() {
+    // 0 -> 1 -> 2 -> 0 has a mean of 1/3, 3 -> 3 has a mean of 1.
+    let successors = |&n: &u32| match n {
+        0 => vec![(1, r64(1.0))],
+        1 => vec![(2, r64(1.0)), (3, r64(0.0))],
+        2 => vec![(0, r64(-1.0))],
+        _ => vec![(3, r64(1.0))],
+    };
+    let (cycle, mean) = minimum_mean_cycle(&[0], successors).unwrap();
+    assert_eq!(cycle.into_iter().sorted().collect_vec(), vec![0, 1, 2]);
+    assert_eq!(mean, r64(1.0) / r64(3.0));
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = XorShiftRng::from_seed([10; 16]);
+    for _ in 0..100 {
+        let size = 8;
+        let graph = random_graph(&mut rng, size, -10..20);
+        let nodes = (0..size).collect_vec();
+        let cycles = elementary_cycles(&nodes, |&n| graph[n].keys().copied().collect_vec())
+            .map(|c| (cycle_weight(&graph, &c), i64::try_from(c.len()).unwrap()))
+            .collect_vec();
+        // Minimum mean cycle, compared as exact fractions.
+        let expected = cycles
+            .iter()
+            .min_by(|&&(w1, l1), &&(w2, l2)| (w1 * l2).cmp(&(w2 * l1)));
+        let result = minimum_mean_cycle(&nodes, successors(&graph));
+        match (expected, result) {
+            (None, None) => (),
+            (Some(&(w, l)), Some((cycle, mean))) => {
+                assert!(cycle.iter().all_unique());
+                let (weight, len) = (
+                    cycle_weight(&graph, &cycle),
+                    i64::try_from(cycle.len()).unwrap(),
+                );
+                assert_eq!(weight * l, w * len);
+                assert_eq!(mean, weight / len);
+            }
+            (expected, result) => panic!("expected {expected:?}, got {result:?}"),
+        }
+        // Negative cycle.
+        let has_negative = cycles.iter().any(|&(w, _)| w < 0);
+        match find_negative_cycle(&nodes, successors(&graph)) {
+            Some(cycle) => {
+                assert!(has_negative);
+                assert!(cycle.iter().all_unique());
+                assert!(cycle_weight(&graph, &cycle) < 0);
+            }
+            None => assert!(!has_negative),
+        }
+    }
+}
+
+#[test]
+fn negative_cycle_far_from_start() {
+    // A long chain leading to a negative cycle.
+    let successors = |&n: &u32| match n {
+        1000 => vec![(1001, 5)],
+        1001 => vec![(1000, -6)],
+        _ => vec![(n + 1, 1)],
+    };
+    let cycle = find_negative_cycle(&[0], successors).unwrap();
+    assert_eq!(cycle.into_iter().sorted().collect_vec(), vec![1000, 1001]);
+}